@@ -0,0 +1,237 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::{MinPriorityQueue, PriorityQueue};
+
+/// Min heap where every node has `D` children.
+///
+/// A larger `D` makes the heap shallower, so `push` does fewer comparisons and `pop` touches
+/// fewer cache lines on large queues, at the cost of more comparisons per level in `pop`.
+///
+/// Complexity:
+/// Runtime O(log_D n) for `push`, O(D * log_D n) for `pop`, O(1) for `peek`, O(n) for building
+/// from a [Vec].
+/// Space O(n)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::DaryHeap;
+///
+/// let mut heap: DaryHeap<_, 4> = [5, 1, 8, 3].into_iter().collect();
+/// heap.push(2);
+/// assert_eq!(heap.pop(), Some(1));
+/// assert_eq!(heap.into_sorted_vec(), vec![2, 3, 5, 8]);
+/// ```
+#[derive(Clone)]
+pub struct DaryHeap<T, const D: usize>
+where
+    T: Ord,
+{
+    items: Vec<T>,
+}
+
+impl<T, const D: usize> DaryHeap<T, D>
+where
+    T: Ord,
+{
+    const ARITY: usize = {
+        assert!(D >= 2, "a d-ary heap needs at least 2 children per node");
+        D
+    };
+
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        let _ = Self::ARITY;
+        Self {
+            items: Vec::with_capacity(capacity),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+    pub fn peek(&self) -> Option<&T> {
+        self.items.first()
+    }
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+        self.sift_up(self.items.len() - 1);
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        if self.items.is_empty() {
+            return None;
+        }
+        let item = self.items.swap_remove(0);
+        if !self.items.is_empty() {
+            self.sift_down(0);
+        }
+        Some(item)
+    }
+    /// Iterate over the items in arbitrary order.
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+    /// Get the items in arbitrary order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+    /// Get the items in ascending order.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        let mut items = self.items;
+        items.sort();
+        items
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / Self::ARITY;
+            if self.items[index] >= self.items[parent] {
+                break;
+            }
+            self.items.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let first_child = Self::ARITY * index + 1;
+            let last_child = (first_child + Self::ARITY).min(self.items.len());
+            let mut smallest = index;
+            for child in first_child..last_child {
+                if self.items[child] < self.items[smallest] {
+                    smallest = child;
+                }
+            }
+            if smallest == index {
+                break;
+            }
+            self.items.swap(index, smallest);
+            index = smallest;
+        }
+    }
+}
+
+impl<T, const D: usize> Default for DaryHeap<T, D>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const D: usize> fmt::Debug for DaryHeap<T, D>
+where
+    T: Ord + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Builds the heap in O(n).
+impl<T, const D: usize> From<Vec<T>> for DaryHeap<T, D>
+where
+    T: Ord,
+{
+    fn from(items: Vec<T>) -> Self {
+        let _ = Self::ARITY;
+        let mut heap = Self { items };
+        if heap.items.len() > 1 {
+            let last_parent = (heap.items.len() - 2) / Self::ARITY;
+            for index in (0..=last_parent).rev() {
+                heap.sift_down(index);
+            }
+        }
+        heap
+    }
+}
+
+impl<T, const D: usize> FromIterator<T> for DaryHeap<T, D>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T, const D: usize> Extend<T> for DaryHeap<T, D>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T, const D: usize> PriorityQueue for DaryHeap<T, D>
+where
+    T: Ord,
+{
+    type Item = T;
+    fn push(&mut self, item: T) {
+        DaryHeap::push(self, item);
+    }
+    fn pop(&mut self) -> Option<T> {
+        DaryHeap::pop(self)
+    }
+    fn peek(&self) -> Option<&T> {
+        DaryHeap::peek(self)
+    }
+    fn len(&self) -> usize {
+        DaryHeap::len(self)
+    }
+}
+
+impl<T, const D: usize> MinPriorityQueue for DaryHeap<T, D> where T: Ord {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::lcg;
+
+    fn check_against_sorting<const D: usize>() {
+        let mut seed = 37;
+        let items: Vec<u64> = (0..300).map(|_| lcg(&mut seed) % 1000).collect();
+        let mut sorted = items.clone();
+        sorted.sort_unstable();
+
+        let mut built = DaryHeap::<_, D>::from(items.clone());
+        let mut pushed = DaryHeap::<_, D>::new();
+        pushed.extend(items);
+        for expected in sorted {
+            assert_eq!(built.peek(), Some(&expected));
+            assert_eq!(built.pop(), Some(expected));
+            assert_eq!(pushed.pop(), Some(expected));
+        }
+        assert!(built.is_empty() && pushed.is_empty());
+    }
+
+    #[test]
+    fn arities() {
+        check_against_sorting::<2>();
+        check_against_sorting::<3>();
+        check_against_sorting::<4>();
+        check_against_sorting::<8>();
+    }
+
+    #[test]
+    fn empty() {
+        let mut heap = DaryHeap::<u32, 4>::default();
+        assert_eq!(heap.peek(), None);
+        assert_eq!(heap.pop(), None);
+        assert!(DaryHeap::<u32, 3>::from(Vec::new()).is_empty());
+    }
+}
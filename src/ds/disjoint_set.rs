@@ -0,0 +1,302 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem::swap;
+
+/// Union-find over the elements `0..len`, with path compression and union by size.
+///
+/// Complexity:
+/// Runtime O(α(n)) amortized for `find` and `union`, where α is the inverse Ackermann function.
+/// Space O(n)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::DisjointSet;
+///
+/// let mut sets = DisjointSet::new(5);
+/// sets.union(0, 1);
+/// sets.union(3, 4);
+/// sets.union(1, 4);
+/// assert!(sets.same_set(0, 3));
+/// assert_eq!(sets.set_size(0), 4);
+/// assert_eq!(sets.components(), vec![vec![0, 1, 3, 4], vec![2]]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    set_count: usize,
+}
+
+impl DisjointSet {
+    /// `len` elements, each in its own set.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            set_count: len,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+    /// Number of disjoint sets.
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+    /// Add a new element in its own set, and return it.
+    pub fn add(&mut self) -> usize {
+        let element = self.parent.len();
+        self.parent.push(element);
+        self.size.push(1);
+        self.set_count += 1;
+        element
+    }
+    /// Get the representative of the set that contains `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut current = element;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+    /// Merge the sets that contain `a` and `b`.
+    ///
+    /// Returns `false` if they were already in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let mut a = self.find(a);
+        let mut b = self.find(b);
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.set_count -= 1;
+        true
+    }
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+    /// Number of elements in the set that contains `element`.
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+    /// Get every set, each one sorted, ordered by their smallest element.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index_of_root = vec![usize::MAX; self.len()];
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.set_count);
+        for element in 0..self.len() {
+            let root = self.find(element);
+            if index_of_root[root] == usize::MAX {
+                index_of_root[root] = components.len();
+                components.push(Vec::with_capacity(self.size[root]));
+            }
+            components[index_of_root[root]].push(element);
+        }
+        components
+    }
+}
+
+/// Union-find that can undo unions, for offline algorithms.
+///
+/// Uses union by size without path compression, so every union can be undone in O(1).
+///
+/// Complexity:
+/// Runtime O(log n) for `find` and `union`, O(1) per undone union.
+/// Space O(n + u)
+/// Where u = number of unions since the oldest snapshot still in use.
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::RollbackDisjointSet;
+///
+/// let mut sets = RollbackDisjointSet::new(4);
+/// sets.union(0, 1);
+/// let snapshot = sets.snapshot();
+/// sets.union(1, 2);
+/// assert!(sets.same_set(0, 2));
+/// sets.rollback(snapshot);
+/// assert!(!sets.same_set(0, 2));
+/// assert!(sets.same_set(0, 1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RollbackDisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    set_count: usize,
+    // Roots that were attached to another root, in order.
+    history: Vec<usize>,
+}
+
+impl RollbackDisjointSet {
+    /// `len` elements, each in its own set.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            set_count: len,
+            history: Vec::new(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+    /// Number of disjoint sets.
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+    /// Get the representative of the set that contains `element`.
+    pub fn find(&self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        root
+    }
+    /// Merge the sets that contain `a` and `b`.
+    ///
+    /// Returns `false` if they were already in the same set. Only successful unions are recorded
+    /// for rollback.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let mut a = self.find(a);
+        let mut b = self.find(b);
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.set_count -= 1;
+        self.history.push(b);
+        true
+    }
+    pub fn same_set(&self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+    /// Number of elements in the set that contains `element`.
+    pub fn set_size(&self, element: usize) -> usize {
+        self.size[self.find(element)]
+    }
+    /// Get a point to <code>[rollback](Self::rollback)</code> to.
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+    /// Undo the last successful union.
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(child) = self.history.pop() else {
+            return false;
+        };
+        let root = self.parent[child];
+        self.parent[child] = child;
+        self.size[root] -= self.size[child];
+        self.set_count += 1;
+        true
+    }
+    /// Undo every union made after `snapshot` was taken.
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn singletons() {
+        let mut sets = DisjointSet::new(3);
+        assert_eq!(sets.set_count(), 3);
+        assert!(!sets.same_set(0, 1));
+        assert_eq!(sets.set_size(2), 1);
+        assert_eq!(sets.components(), vec![vec![0], vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn union_twice() {
+        let mut sets = DisjointSet::new(3);
+        assert!(sets.union(0, 2));
+        assert!(!sets.union(2, 0));
+        assert_eq!(sets.set_count(), 2);
+        assert_eq!(sets.set_size(2), 2);
+    }
+
+    #[test]
+    fn long_chain() {
+        let mut sets = DisjointSet::new(1000);
+        for i in 1..1000 {
+            sets.union(i - 1, i);
+        }
+        assert_eq!(sets.set_count(), 1);
+        assert_eq!(sets.set_size(500), 1000);
+        assert!(sets.same_set(0, 999));
+    }
+
+    #[test]
+    fn add_elements() {
+        let mut sets = DisjointSet::new(0);
+        assert!(sets.is_empty());
+        let a = sets.add();
+        let b = sets.add();
+        sets.union(a, b);
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets.components(), vec![vec![0, 1]]);
+    }
+
+    #[test]
+    fn rollback_in_order() {
+        let mut sets = RollbackDisjointSet::new(5);
+        sets.union(0, 1);
+        let first = sets.snapshot();
+        sets.union(2, 3);
+        let second = sets.snapshot();
+        sets.union(1, 3);
+        sets.union(3, 4);
+        assert_eq!(sets.set_count(), 1);
+        assert_eq!(sets.set_size(0), 5);
+
+        sets.rollback(second);
+        assert_eq!(sets.set_count(), 3);
+        assert!(sets.same_set(2, 3));
+        assert!(!sets.same_set(1, 3));
+        assert_eq!(sets.set_size(4), 1);
+
+        sets.rollback(first);
+        assert_eq!(sets.set_count(), 4);
+        assert!(!sets.same_set(2, 3));
+        assert!(sets.same_set(0, 1));
+    }
+
+    #[test]
+    fn failed_union_is_not_recorded() {
+        let mut sets = RollbackDisjointSet::new(2);
+        sets.union(0, 1);
+        let snapshot = sets.snapshot();
+        assert!(!sets.union(1, 0));
+        assert_eq!(sets.snapshot(), snapshot);
+        assert!(sets.undo());
+        assert!(!sets.undo());
+    }
+}
@@ -0,0 +1,318 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, RangeBounds, Sub};
+
+use super::segment_tree::to_bounds;
+
+/// Fenwick tree (binary indexed tree), with point updates and prefix sums.
+///
+/// Complexity:
+/// Runtime O(log n) for `add`, `prefix_sum`, `range_sum` and `lower_bound`, O(n) for building.
+/// Space O(n)
+///
+/// # Examples
+///
+/// Counting how many [Interval](crate::intervals::Interval)s cover a point, after compressing the
+/// coordinates of their endpoints:
+///
+/// ```
+/// use kupsy::ds::FenwickTree;
+/// use kupsy::intervals::{CoordinateCompressor, Interval};
+///
+/// let intervals = [Interval {start: 10, end: 50}, Interval {start: 20, end: 30}, Interval {start: 25, end: 90}];
+/// let compressor = CoordinateCompressor::new(&intervals);
+///
+/// let mut tree = FenwickTree::new(compressor.len());
+/// for interval in compressor.compress_all(&intervals).unwrap() {
+///     tree.add(interval.start, 1);
+///     tree.add(interval.end, -1);
+/// }
+/// let covering = |point| tree.prefix_sum(compressor.upper_bound(&point));
+///
+/// assert_eq!(covering(27), 3);
+/// assert_eq!(covering(30), 2);
+/// assert_eq!(covering(95), 0);
+/// ```
+pub struct FenwickTree<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    // 1-based, tree[0] is unused.
+    tree: Vec<T>,
+}
+
+impl<T> FenwickTree<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Fenwick tree with `len` elements, all set to `T::default()`.
+    pub fn new(len: usize) -> Self {
+        Self {
+            tree: vec![T::default(); len + 1],
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Add `delta` to the element at `index`.
+    pub fn add(&mut self, index: usize, delta: T) {
+        assert!(index < self.len(), "index out of bounds");
+        let mut node = index + 1;
+        while node < self.tree.len() {
+            self.tree[node] = self.tree[node] + delta;
+            node += lowest_bit(node);
+        }
+    }
+    /// Sum of the first `end` elements.
+    pub fn prefix_sum(&self, end: usize) -> T {
+        assert!(end <= self.len(), "index out of bounds");
+        let mut sum = T::default();
+        let mut node = end;
+        while node > 0 {
+            sum = sum + self.tree[node];
+            node -= lowest_bit(node);
+        }
+        sum
+    }
+    /// Sum of the elements in `range`.
+    pub fn range_sum<R>(&self, range: R) -> T
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = to_bounds(range, self.len());
+        self.prefix_sum(end) - self.prefix_sum(start)
+    }
+    pub fn get(&self, index: usize) -> T {
+        self.range_sum(index..=index)
+    }
+}
+
+impl<T> FenwickTree<T>
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Find the first index where the prefix sum reaches `target`.
+    ///
+    /// Returns the smallest `i` where `prefix_sum(i + 1) >= target`, or `len()` if there's none.
+    ///
+    /// assumptions:
+    /// No element is negative.
+    pub fn lower_bound(&self, target: T) -> usize {
+        let len = self.len();
+        let mut position = 0;
+        let mut remaining = target;
+        let mut step = if len == 0 { 0 } else { 1 << len.ilog2() };
+        while step > 0 {
+            let next = position + step;
+            if next <= len && self.tree[next] < remaining {
+                position = next;
+                remaining = remaining - self.tree[next];
+            }
+            step /= 2;
+        }
+        position
+    }
+}
+
+impl<T> From<Vec<T>> for FenwickTree<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    fn from(values: Vec<T>) -> Self {
+        let mut tree = Vec::with_capacity(values.len() + 1);
+        tree.push(T::default());
+        tree.extend(values);
+        for node in 1..tree.len() {
+            let parent = node + lowest_bit(node);
+            if parent < tree.len() {
+                tree[parent] = tree[parent] + tree[node];
+            }
+        }
+        Self { tree }
+    }
+}
+
+/// 2D Fenwick tree, with point updates and sums over rectangles.
+///
+/// Complexity:
+/// Runtime O(log r * log c) for `add`, `prefix_sum` and `rect_sum`.
+/// Space O(r * c)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::FenwickTree2D;
+///
+/// let mut tree = FenwickTree2D::new(3, 4);
+/// tree.add(0, 1, 5);
+/// tree.add(2, 3, 2);
+/// assert_eq!(tree.rect_sum(.., ..), 7);
+/// assert_eq!(tree.rect_sum(1.., 2..), 2);
+/// ```
+pub struct FenwickTree2D<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    rows: usize,
+    cols: usize,
+    // 1-based, row 0 and column 0 are unused.
+    tree: Vec<T>,
+}
+
+impl<T> FenwickTree2D<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            tree: vec![T::default(); (rows + 1) * (cols + 1)],
+        }
+    }
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+    /// Add `delta` to the element at (`row`, `col`).
+    pub fn add(&mut self, row: usize, col: usize, delta: T) {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        let mut r = row + 1;
+        while r <= self.rows {
+            let mut c = col + 1;
+            while c <= self.cols {
+                let node = r * (self.cols + 1) + c;
+                self.tree[node] = self.tree[node] + delta;
+                c += lowest_bit(c);
+            }
+            r += lowest_bit(r);
+        }
+    }
+    /// Sum of the elements in the first `rows_end` rows and `cols_end` columns.
+    pub fn prefix_sum(&self, rows_end: usize, cols_end: usize) -> T {
+        assert!(
+            rows_end <= self.rows && cols_end <= self.cols,
+            "index out of bounds"
+        );
+        let mut sum = T::default();
+        let mut r = rows_end;
+        while r > 0 {
+            let mut c = cols_end;
+            while c > 0 {
+                sum = sum + self.tree[r * (self.cols + 1) + c];
+                c -= lowest_bit(c);
+            }
+            r -= lowest_bit(r);
+        }
+        sum
+    }
+    /// Sum of the elements inside the rectangle.
+    pub fn rect_sum<R, C>(&self, rows: R, cols: C) -> T
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (row_start, row_end) = to_bounds(rows, self.rows);
+        let (col_start, col_end) = to_bounds(cols, self.cols);
+        self.prefix_sum(row_end, col_end) + self.prefix_sum(row_start, col_start)
+            - self.prefix_sum(row_start, col_end)
+            - self.prefix_sum(row_end, col_start)
+    }
+}
+
+fn lowest_bit(n: usize) -> usize {
+    n & n.wrapping_neg()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::lcg;
+
+    #[test]
+    fn empty() {
+        let tree = FenwickTree::<i32>::new(0);
+        assert!(tree.is_empty());
+        assert_eq!(tree.prefix_sum(0), 0);
+        assert_eq!(tree.lower_bound(1), 0);
+    }
+
+    #[test]
+    fn prefix_and_range_sums() {
+        let mut tree = FenwickTree::from(vec![3, 1, 4, 1, 5, 9, 2, 6]);
+        assert_eq!(tree.prefix_sum(8), 31);
+        assert_eq!(tree.prefix_sum(3), 8);
+        assert_eq!(tree.range_sum(2..5), 10);
+        assert_eq!(tree.get(5), 9);
+        tree.add(5, -9);
+        assert_eq!(tree.range_sum(4..=6), 7);
+    }
+
+    #[test]
+    fn from_matches_adds() {
+        let mut seed = 11;
+        let values: Vec<i64> = (0..37).map(|_| lcg(&mut seed) as i64 % 50).collect();
+        let built = FenwickTree::from(values.clone());
+        let mut added = FenwickTree::new(values.len());
+        for (i, &value) in values.iter().enumerate() {
+            added.add(i, value);
+        }
+        for end in 0..=values.len() {
+            let expected: i64 = values[..end].iter().sum();
+            assert_eq!(built.prefix_sum(end), expected);
+            assert_eq!(added.prefix_sum(end), expected);
+        }
+    }
+
+    #[test]
+    fn lower_bound() {
+        let tree = FenwickTree::from(vec![2_u32, 0, 3, 1, 0, 4]);
+        assert_eq!(tree.lower_bound(0), 0);
+        assert_eq!(tree.lower_bound(1), 0);
+        assert_eq!(tree.lower_bound(2), 0);
+        assert_eq!(tree.lower_bound(3), 2);
+        assert_eq!(tree.lower_bound(5), 2);
+        assert_eq!(tree.lower_bound(6), 3);
+        assert_eq!(tree.lower_bound(7), 5);
+        assert_eq!(tree.lower_bound(10), 5);
+        assert_eq!(tree.lower_bound(11), 6);
+    }
+
+    #[test]
+    fn two_dimensional() {
+        let mut seed = 5;
+        let (rows, cols) = (6, 9);
+        let mut grid = vec![vec![0_i64; cols]; rows];
+        let mut tree = FenwickTree2D::new(rows, cols);
+        for _ in 0..40 {
+            let (r, c) = (
+                lcg(&mut seed) as usize % rows,
+                lcg(&mut seed) as usize % cols,
+            );
+            let delta = lcg(&mut seed) as i64 % 10 - 3;
+            grid[r][c] += delta;
+            tree.add(r, c, delta);
+        }
+        for row_start in 0..rows {
+            for row_end in row_start..=rows {
+                for col_start in 0..cols {
+                    for col_end in col_start..=cols {
+                        let expected: i64 = grid[row_start..row_end]
+                            .iter()
+                            .map(|row| row[col_start..col_end].iter().sum::<i64>())
+                            .sum();
+                        assert_eq!(
+                            tree.rect_sum(row_start..row_end, col_start..col_end),
+                            expected
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,12 @@
 use core::cmp::Reverse;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
 
-use alloc::collections::BinaryHeap;
+use alloc::collections::binary_heap::{self, BinaryHeap, PeekMut};
+use alloc::vec::Vec;
 
 /// Thin wrapper around std::collections::BinaryHeap with std::cmp::Reverse
+#[derive(Clone)]
 pub struct MinHeap<T>
 where
     T: Ord,
@@ -18,21 +22,179 @@ where
             heap: BinaryHeap::new(),
         }
     }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: BinaryHeap::with_capacity(capacity),
+        }
+    }
     pub fn pop(&mut self) -> Option<T> {
         self.heap.pop().map(|val| val.0)
     }
     pub fn peek(&self) -> Option<&T> {
         self.heap.peek().map(|val| &val.0)
     }
+    /// Mutable access to the smallest item. The heap is fixed when the guard is dropped.
+    pub fn peek_mut(&mut self) -> Option<MinHeapPeekMut<'_, T>> {
+        self.heap.peek_mut().map(|inner| MinHeapPeekMut { inner })
+    }
     pub fn push(&mut self, item: T) {
         self.heap.push(Reverse(item));
     }
     pub fn len(&self) -> usize {
         self.heap.len()
     }
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.heap.clear();
+    }
+    /// Move every item of `other` into `self`, leaving `other` empty.
+    pub fn append(&mut self, other: &mut Self) {
+        self.heap.append(&mut other.heap);
+    }
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.heap.retain(|val| f(&val.0));
+    }
+    /// Iterate over the items in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.heap.iter().map(|val| &val.0)
+    }
+    /// Remove every item, in arbitrary order.
+    pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        self.heap.drain().map(|val| val.0)
+    }
+    /// Get the items in arbitrary order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.heap.into_iter().map(|val| val.0).collect()
+    }
+    /// Get the items in ascending order.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        let mut sorted: Vec<T> = self
+            .heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|val| val.0)
+            .collect();
+        sorted.reverse();
+        sorted
+    }
+}
+
+/// Mutable access to the smallest item of a <code>[MinHeap]</code>, returned by
+/// <code>[MinHeap::peek_mut]</code>.
+pub struct MinHeapPeekMut<'a, T>
+where
+    T: Ord,
+{
+    inner: PeekMut<'a, Reverse<T>>,
+}
+impl<T> MinHeapPeekMut<'_, T>
+where
+    T: Ord,
+{
+    /// Remove the peeked item from the heap, and return it.
+    pub fn pop(this: Self) -> T {
+        PeekMut::pop(this.inner).0
+    }
+}
+impl<T> Deref for MinHeapPeekMut<'_, T>
+where
+    T: Ord,
+{
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.inner.0
+    }
+}
+impl<T> DerefMut for MinHeapPeekMut<'_, T>
+where
+    T: Ord,
+{
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.inner.0
+    }
+}
+
+impl<T> Default for MinHeap<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T> fmt::Debug for MinHeap<T>
+where
+    T: Ord + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+/// Builds the heap in O(n).
+impl<T> From<Vec<T>> for MinHeap<T>
+where
+    T: Ord,
+{
+    fn from(items: Vec<T>) -> Self {
+        Self {
+            heap: BinaryHeap::from(items.into_iter().map(Reverse).collect::<Vec<_>>()),
+        }
+    }
+}
+impl<T> FromIterator<T> for MinHeap<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            heap: iter.into_iter().map(Reverse).collect(),
+        }
+    }
+}
+impl<T> Extend<T> for MinHeap<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.heap.extend(iter.into_iter().map(Reverse));
+    }
+}
+impl<T> IntoIterator for MinHeap<T>
+where
+    T: Ord,
+{
+    type Item = T;
+    type IntoIter = MinHeapIntoIter<T>;
+    /// Iterate over the items in arbitrary order.
+    fn into_iter(self) -> Self::IntoIter {
+        MinHeapIntoIter {
+            inner: self.heap.into_iter(),
+        }
+    }
 }
 
+/// Owning iterator over the items of a <code>[MinHeap]</code>, in arbitrary order.
+pub struct MinHeapIntoIter<T> {
+    inner: binary_heap::IntoIter<Reverse<T>>,
+}
+impl<T> Iterator for MinHeapIntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        self.inner.next().map(|val| val.0)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<T> ExactSizeIterator for MinHeapIntoIter<T> {}
+
 /// Thin wrapper around std::collections::BinaryHeap
+#[derive(Clone)]
 pub struct MaxHeap<T>
 where
     T: Ord,
@@ -48,16 +210,192 @@ where
             heap: BinaryHeap::new(),
         }
     }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: BinaryHeap::with_capacity(capacity),
+        }
+    }
     pub fn pop(&mut self) -> Option<T> {
         self.heap.pop()
     }
-    pub fn peek(&mut self) -> Option<&T> {
+    pub fn peek(&self) -> Option<&T> {
         self.heap.peek()
     }
+    /// Mutable access to the largest item. The heap is fixed when the guard is dropped.
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T>> {
+        self.heap.peek_mut()
+    }
     pub fn push(&mut self, item: T) {
         self.heap.push(item);
     }
     pub fn len(&self) -> usize {
         self.heap.len()
     }
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.heap.clear();
+    }
+    /// Move every item of `other` into `self`, leaving `other` empty.
+    pub fn append(&mut self, other: &mut Self) {
+        self.heap.append(&mut other.heap);
+    }
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.heap.retain(f);
+    }
+    /// Iterate over the items in arbitrary order.
+    pub fn iter(&self) -> binary_heap::Iter<'_, T> {
+        self.heap.iter()
+    }
+    /// Remove every item, in arbitrary order.
+    pub fn drain(&mut self) -> binary_heap::Drain<'_, T> {
+        self.heap.drain()
+    }
+    /// Get the items in arbitrary order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.heap.into_vec()
+    }
+    /// Get the items in ascending order.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.heap.into_sorted_vec()
+    }
+}
+
+impl<T> Default for MaxHeap<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T> fmt::Debug for MaxHeap<T>
+where
+    T: Ord + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+/// Builds the heap in O(n).
+impl<T> From<Vec<T>> for MaxHeap<T>
+where
+    T: Ord,
+{
+    fn from(items: Vec<T>) -> Self {
+        Self {
+            heap: BinaryHeap::from(items),
+        }
+    }
+}
+impl<T> FromIterator<T> for MaxHeap<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            heap: iter.into_iter().collect(),
+        }
+    }
+}
+impl<T> Extend<T> for MaxHeap<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.heap.extend(iter);
+    }
+}
+impl<T> IntoIterator for MaxHeap<T>
+where
+    T: Ord,
+{
+    type Item = T;
+    type IntoIter = binary_heap::IntoIter<T>;
+    /// Iterate over the items in arbitrary order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.heap.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use alloc::vec;
+
+    #[test]
+    fn min_heap_order() {
+        let mut heap = MinHeap::from(vec![5, 1, 4, 1, 3]);
+        assert_eq!(heap.len(), 5);
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(3));
+        heap.clear();
+        assert!(heap.is_empty());
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn min_heap_peek_mut() {
+        let mut heap: MinHeap<_> = [3, 7, 5].into_iter().collect();
+        *heap.peek_mut().unwrap() = 9;
+        assert_eq!(heap.peek(), Some(&5));
+        let top = heap.peek_mut().unwrap();
+        assert_eq!(MinHeapPeekMut::pop(top), 5);
+        assert_eq!(heap.into_sorted_vec(), vec![7, 9]);
+    }
+
+    #[test]
+    fn max_heap_peek_mut() {
+        let mut heap: MaxHeap<_> = [3, 7, 5].into_iter().collect();
+        *heap.peek_mut().unwrap() = 1;
+        assert_eq!(heap.peek(), Some(&5));
+        assert_eq!(heap.into_sorted_vec(), vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn append_retain_extend() {
+        let mut a = MinHeap::from(vec![4, 8]);
+        let mut b = MinHeap::from(vec![1, 6]);
+        a.append(&mut b);
+        assert!(b.is_empty());
+        a.retain(|&x| x % 2 == 0);
+        a.extend([2, 10]);
+        assert_eq!(a.into_sorted_vec(), vec![2, 4, 6, 8, 10]);
+
+        let mut a = MaxHeap::from(vec![4, 8]);
+        let mut b = MaxHeap::from(vec![1, 6]);
+        a.append(&mut b);
+        a.retain(|&x| x > 1);
+        a.extend([2]);
+        assert_eq!(a.pop(), Some(8));
+        assert_eq!(a.into_sorted_vec(), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn drain_and_into_iter() {
+        let mut heap = MinHeap::from(vec![2, 1, 3]);
+        let mut drained: Vec<_> = heap.drain().collect();
+        drained.sort_unstable();
+        assert_eq!(drained, vec![1, 2, 3]);
+        assert!(heap.is_empty());
+
+        let heap = MaxHeap::from(vec![2, 1, 3]);
+        let mut items: Vec<_> = heap.clone().into_iter().collect();
+        items.sort_unstable();
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(heap.len(), 3);
+    }
+
+    #[test]
+    fn debug_lists_items() {
+        let heap = MinHeap::from(vec![1]);
+        assert_eq!(format!("{:?}", heap), "[1]");
+        assert_eq!(format!("{:?}", MaxHeap::<u32>::default()), "[]");
+    }
 }
@@ -0,0 +1,348 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// How a <code>[HeapBy]</code> orders its items.
+pub trait HeapOrder<T> {
+    /// Whether the largest item is popped first, instead of the smallest.
+    const MAX: bool;
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+/// Smallest first, according to a comparator.
+#[derive(Clone)]
+pub struct MinBy<F>(F);
+/// Largest first, according to a comparator.
+#[derive(Clone)]
+pub struct MaxBy<F>(F);
+/// Smallest key first.
+#[derive(Clone)]
+pub struct MinByKey<K, F> {
+    key: F,
+    _key: PhantomData<fn() -> K>,
+}
+/// Largest key first.
+#[derive(Clone)]
+pub struct MaxByKey<K, F> {
+    key: F,
+    _key: PhantomData<fn() -> K>,
+}
+
+impl<F> MinBy<F> {
+    pub fn new(compare: F) -> Self {
+        Self(compare)
+    }
+}
+impl<F> MaxBy<F> {
+    pub fn new(compare: F) -> Self {
+        Self(compare)
+    }
+}
+impl<K, F> MinByKey<K, F> {
+    pub fn new(key: F) -> Self {
+        Self {
+            key,
+            _key: PhantomData,
+        }
+    }
+}
+impl<K, F> MaxByKey<K, F> {
+    pub fn new(key: F) -> Self {
+        Self {
+            key,
+            _key: PhantomData,
+        }
+    }
+}
+
+impl<T, F> HeapOrder<T> for MinBy<F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    const MAX: bool = false;
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        (self.0)(a, b)
+    }
+}
+impl<T, F> HeapOrder<T> for MaxBy<F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    const MAX: bool = true;
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        (self.0)(a, b)
+    }
+}
+impl<T, K, F> HeapOrder<T> for MinByKey<K, F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    const MAX: bool = false;
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        (self.key)(a).cmp(&(self.key)(b))
+    }
+}
+impl<T, K, F> HeapOrder<T> for MaxByKey<K, F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    const MAX: bool = true;
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        (self.key)(a).cmp(&(self.key)(b))
+    }
+}
+
+/// Min heap ordered by a comparator, for items that aren't [Ord], like floats.
+pub type MinHeapBy<T, F> = HeapBy<T, MinBy<F>>;
+/// Max heap ordered by a comparator, for items that aren't [Ord], like floats.
+pub type MaxHeapBy<T, F> = HeapBy<T, MaxBy<F>>;
+/// Min heap ordered by a key of its items.
+pub type MinHeapByKey<T, K, F> = HeapBy<T, MinByKey<K, F>>;
+/// Max heap ordered by a key of its items.
+pub type MaxHeapByKey<T, K, F> = HeapBy<T, MaxByKey<K, F>>;
+
+/// Binary heap ordered by a <code>[HeapOrder]</code>, instead of by [Ord].
+///
+/// Usually used through <code>[MinHeapBy]</code>, <code>[MaxHeapBy]</code>,
+/// <code>[MinHeapByKey]</code> or <code>[MaxHeapByKey]</code>. Keys are computed on every
+/// comparison, so they should be cheap.
+///
+/// Complexity:
+/// Runtime O(log n) for `push` and `pop`, O(1) for `peek`, O(n) for building from a [Vec].
+/// Space O(n)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::{MaxHeapBy, MinHeapByKey};
+/// use kupsy::intervals::Interval;
+///
+/// let mut by_end = MinHeapByKey::new(|interval: &Interval<u32>| interval.end);
+/// by_end.push(Interval {start: 1, end: 9});
+/// by_end.push(Interval {start: 4, end: 6});
+/// assert_eq!(by_end.pop(), Some(Interval {start: 4, end: 6}));
+///
+/// let mut floats = MaxHeapBy::new(|a: &f64, b: &f64| a.total_cmp(b));
+/// floats.extend([0.5, 2.5, -1.0]);
+/// assert_eq!(floats.pop(), Some(2.5));
+/// ```
+#[derive(Clone)]
+pub struct HeapBy<T, O>
+where
+    O: HeapOrder<T>,
+{
+    items: Vec<T>,
+    order: O,
+}
+
+impl<T, F> HeapBy<T, MinBy<F>>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    pub fn new(compare: F) -> Self {
+        Self::with_order(MinBy::new(compare))
+    }
+}
+impl<T, F> HeapBy<T, MaxBy<F>>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    pub fn new(compare: F) -> Self {
+        Self::with_order(MaxBy::new(compare))
+    }
+}
+impl<T, K, F> HeapBy<T, MinByKey<K, F>>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    pub fn new(key: F) -> Self {
+        Self::with_order(MinByKey::new(key))
+    }
+}
+impl<T, K, F> HeapBy<T, MaxByKey<K, F>>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    pub fn new(key: F) -> Self {
+        Self::with_order(MaxByKey::new(key))
+    }
+}
+
+impl<T, O> HeapBy<T, O>
+where
+    O: HeapOrder<T>,
+{
+    pub fn with_order(order: O) -> Self {
+        Self {
+            items: Vec::new(),
+            order,
+        }
+    }
+    /// Build the heap from `items` in O(n).
+    pub fn from_vec(items: Vec<T>, order: O) -> Self {
+        let mut heap = Self { items, order };
+        for index in (0..heap.items.len() / 2).rev() {
+            heap.sift_down(index);
+        }
+        heap
+    }
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+    pub fn peek(&self) -> Option<&T> {
+        self.items.first()
+    }
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+        self.sift_up(self.items.len() - 1);
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        if self.items.is_empty() {
+            return None;
+        }
+        let item = self.items.swap_remove(0);
+        if !self.items.is_empty() {
+            self.sift_down(0);
+        }
+        Some(item)
+    }
+    /// Iterate over the items in arbitrary order.
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+    /// Get the items in arbitrary order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+    /// Get the items in ascending order, same as for <code>[MinHeap]</code> and
+    /// <code>[MaxHeap]</code>.
+    ///
+    /// [MinHeap]: super::MinHeap
+    /// [MaxHeap]: super::MaxHeap
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        let Self { mut items, order } = self;
+        items.sort_by(|a, b| order.compare(a, b));
+        items
+    }
+
+    /// Whether `a` should be popped before `b`.
+    fn before(&self, a: &T, b: &T) -> bool {
+        let ordering = self.order.compare(a, b);
+        if O::MAX {
+            ordering == Ordering::Greater
+        } else {
+            ordering == Ordering::Less
+        }
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if !self.before(&self.items[index], &self.items[parent]) {
+                break;
+            }
+            self.items.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let left = 2 * index + 1;
+            let right = left + 1;
+            let mut first = index;
+            if left < self.items.len() && self.before(&self.items[left], &self.items[first]) {
+                first = left;
+            }
+            if right < self.items.len() && self.before(&self.items[right], &self.items[first]) {
+                first = right;
+            }
+            if first == index {
+                break;
+            }
+            self.items.swap(index, first);
+            index = first;
+        }
+    }
+}
+
+impl<T, O> Extend<T> for HeapBy<T, O>
+where
+    O: HeapOrder<T>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T, O> fmt::Debug for HeapBy<T, O>
+where
+    T: fmt::Debug,
+    O: HeapOrder<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::lcg;
+    use alloc::vec;
+
+    #[test]
+    fn empty() {
+        let mut heap = MinHeapBy::new(|a: &u32, b: &u32| a.cmp(b));
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek(), None);
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn floats() {
+        let mut heap = MinHeapBy::new(|a: &f64, b: &f64| a.total_cmp(b));
+        heap.extend([2.5, -0.5, 1.0]);
+        assert_eq!(heap.pop(), Some(-0.5));
+        assert_eq!(heap.into_sorted_vec(), vec![1.0, 2.5]);
+    }
+
+    #[test]
+    fn max_by_key() {
+        let mut heap = MaxHeapByKey::new(|&(_, priority): &(char, u32)| priority);
+        heap.extend([('a', 2), ('b', 7), ('c', 4)]);
+        assert_eq!(heap.peek(), Some(&('b', 7)));
+        assert_eq!(heap.into_sorted_vec(), vec![('a', 2), ('c', 4), ('b', 7)]);
+    }
+
+    #[test]
+    fn from_vec_matches_sorting() {
+        let mut seed = 31;
+        let items: Vec<u64> = (0..200).map(|_| lcg(&mut seed) % 500).collect();
+        let mut sorted = items.clone();
+        sorted.sort_unstable();
+
+        let mut min = HeapBy::from_vec(items.clone(), MinByKey::new(|&x: &u64| x));
+        let mut max = HeapBy::from_vec(items, MaxBy::new(|a: &u64, b: &u64| a.cmp(b)));
+        for &expected in &sorted {
+            assert_eq!(min.pop(), Some(expected));
+        }
+        for &expected in sorted.iter().rev() {
+            assert_eq!(max.pop(), Some(expected));
+        }
+        assert!(min.is_empty() && max.is_empty());
+    }
+}
@@ -0,0 +1,246 @@
+use alloc::vec::Vec;
+use core::hash::Hash;
+use core::mem::replace;
+
+use hashbrown::HashMap;
+
+/// Min heap of keys, where the priority of a key can be changed after it was pushed.
+///
+/// Complexity:
+/// Runtime O(log n) for `push`, `pop`, `change_priority` and `remove`, O(1) for `peek` and
+/// `contains`.
+/// Space O(n)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::IndexedMinHeap;
+///
+/// let mut heap = IndexedMinHeap::new();
+/// heap.push("a", 5);
+/// heap.push("b", 3);
+/// heap.push("c", 4);
+/// heap.change_priority(&"a", 1);
+/// heap.remove(&"c");
+/// assert_eq!(heap.pop(), Some(("a", 1)));
+/// assert_eq!(heap.pop(), Some(("b", 3)));
+/// assert_eq!(heap.pop(), None);
+/// ```
+pub struct IndexedMinHeap<K, P>
+where
+    K: Hash + Eq + Clone,
+    P: Ord,
+{
+    heap: Vec<(K, P)>,
+    positions: HashMap<K, usize>,
+}
+
+impl<K, P> IndexedMinHeap<K, P>
+where
+    K: Hash + Eq + Clone,
+    P: Ord,
+{
+    pub fn new() -> Self {
+        Self {
+            heap: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: Vec::with_capacity(capacity),
+            positions: HashMap::with_capacity(capacity),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    pub fn contains(&self, key: &K) -> bool {
+        self.positions.contains_key(key)
+    }
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        self.positions.get(key).map(|&i| &self.heap[i].1)
+    }
+    pub fn peek(&self) -> Option<(&K, &P)> {
+        self.heap.first().map(|(key, priority)| (key, priority))
+    }
+    /// Push `key` with `priority`.
+    ///
+    /// If `key` is already in the heap, its priority is changed instead, and the old one is
+    /// returned.
+    pub fn push(&mut self, key: K, priority: P) -> Option<P> {
+        if self.contains(&key) {
+            return self.change_priority(&key, priority);
+        }
+        let index = self.heap.len();
+        self.positions.insert(key.clone(), index);
+        self.heap.push((key, priority));
+        self.sift_up(index);
+        None
+    }
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        Some(self.remove_at(0))
+    }
+    /// Change the priority of `key`, and return the old one.
+    ///
+    /// Returns `None`, and does nothing, if `key` isn't in the heap.
+    pub fn change_priority(&mut self, key: &K, priority: P) -> Option<P> {
+        let index = *self.positions.get(key)?;
+        let old = replace(&mut self.heap[index].1, priority);
+        if self.heap[index].1 < old {
+            self.sift_up(index);
+        } else {
+            self.sift_down(index);
+        }
+        Some(old)
+    }
+    /// Remove `key`, and return its priority.
+    pub fn remove(&mut self, key: &K) -> Option<P> {
+        let index = *self.positions.get(key)?;
+        Some(self.remove_at(index).1)
+    }
+    pub fn clear(&mut self) {
+        self.heap.clear();
+        self.positions.clear();
+    }
+
+    fn remove_at(&mut self, index: usize) -> (K, P) {
+        let last = self.heap.len() - 1;
+        self.swap(index, last);
+        let removed = self.heap.pop().unwrap();
+        self.positions.remove(&removed.0);
+        if index < self.heap.len() {
+            // The element moved into `index` came from the bottom, but might be smaller than its
+            // new parent when `index` is in a different subtree.
+            self.sift_up(index);
+            self.sift_down(index);
+        }
+        removed
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        *self.positions.get_mut(&self.heap[a].0).unwrap() = a;
+        *self.positions.get_mut(&self.heap[b].0).unwrap() = b;
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.heap[index].1 >= self.heap[parent].1 {
+                break;
+            }
+            self.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let left = 2 * index + 1;
+            let right = left + 1;
+            let mut smallest = index;
+            if left < self.heap.len() && self.heap[left].1 < self.heap[smallest].1 {
+                smallest = left;
+            }
+            if right < self.heap.len() && self.heap[right].1 < self.heap[smallest].1 {
+                smallest = right;
+            }
+            if smallest == index {
+                break;
+            }
+            self.swap(index, smallest);
+            index = smallest;
+        }
+    }
+}
+
+impl<K, P> Default for IndexedMinHeap<K, P>
+where
+    K: Hash + Eq + Clone,
+    P: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::lcg;
+    use alloc::vec;
+
+    #[test]
+    fn empty() {
+        let mut heap = IndexedMinHeap::<u32, u32>::new();
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek(), None);
+        assert_eq!(heap.pop(), None);
+        assert_eq!(heap.remove(&1), None);
+        assert_eq!(heap.change_priority(&1, 3), None);
+    }
+
+    #[test]
+    fn push_existing_key_changes_priority() {
+        let mut heap = IndexedMinHeap::new();
+        assert_eq!(heap.push('a', 3), None);
+        assert_eq!(heap.push('b', 2), None);
+        assert_eq!(heap.push('a', 1), Some(3));
+        assert_eq!(heap.len(), 2);
+        assert_eq!(heap.peek(), Some((&'a', &1)));
+    }
+
+    #[test]
+    fn increase_priority() {
+        let mut heap = IndexedMinHeap::new();
+        for (key, priority) in [(0, 1), (1, 2), (2, 3), (3, 4)] {
+            heap.push(key, priority);
+        }
+        assert_eq!(heap.change_priority(&0, 10), Some(1));
+        assert_eq!(heap.priority(&0), Some(&10));
+        let order: Vec<_> = core::iter::from_fn(|| heap.pop()).map(|(k, _)| k).collect();
+        assert_eq!(order, vec![1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut seed = 1;
+        let mut heap = IndexedMinHeap::new();
+        let mut expected: Vec<Option<u64>> = vec![None; 50];
+        for _ in 0..2000 {
+            let key = lcg(&mut seed) as usize % 50;
+            let priority = lcg(&mut seed) % 100;
+            match lcg(&mut seed) % 4 {
+                0 => {
+                    assert_eq!(heap.push(key, priority), expected[key]);
+                    expected[key] = Some(priority);
+                }
+                1 => {
+                    assert_eq!(heap.change_priority(&key, priority), expected[key]);
+                    if expected[key].is_some() {
+                        expected[key] = Some(priority);
+                    }
+                }
+                2 => {
+                    assert_eq!(heap.remove(&key), expected[key].take());
+                }
+                _ => {
+                    let min = expected.iter().flatten().min().copied();
+                    let popped = heap.pop();
+                    assert_eq!(popped.map(|(_, p)| p), min);
+                    if let Some((key, _)) = popped {
+                        expected[key] = None;
+                    }
+                }
+            }
+            assert_eq!(heap.len(), expected.iter().flatten().count());
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use alloc::vec::Vec;
+
+/// Double ended priority queue, with access to both the smallest and the largest element.
+///
+/// Stored in a single array, where even levels are ordered like a min heap and odd levels like a
+/// max heap.
+///
+/// Complexity:
+/// Runtime O(log n) for `push`, `pop_min` and `pop_max`, O(1) for `peek_min` and `peek_max`, O(n)
+/// for building from a [Vec].
+/// Space O(n)
+///
+/// # Examples
+///
+/// Keeping the 3 best scores, while evicting the worst one:
+///
+/// ```
+/// use kupsy::ds::MinMaxHeap;
+///
+/// let mut best = MinMaxHeap::new();
+/// for score in [5, 1, 8, 3, 9, 2] {
+///     best.push(score);
+///     if best.len() > 3 {
+///         best.pop_min();
+///     }
+/// }
+/// assert_eq!(best.peek_max(), Some(&9));
+/// assert_eq!(best.peek_min(), Some(&5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MinMaxHeap<T>
+where
+    T: Ord,
+{
+    heap: Vec<T>,
+}
+
+impl<T> MinMaxHeap<T>
+where
+    T: Ord,
+{
+    pub fn new() -> Self {
+        Self { heap: Vec::new() }
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: Vec::with_capacity(capacity),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.heap.clear();
+    }
+    /// Get the elements, in no particular order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.heap
+    }
+    pub fn push(&mut self, item: T) {
+        self.heap.push(item);
+        self.bubble_up(self.heap.len() - 1);
+    }
+    pub fn peek_min(&self) -> Option<&T> {
+        self.heap.first()
+    }
+    pub fn peek_max(&self) -> Option<&T> {
+        self.max_index().map(|i| &self.heap[i])
+    }
+    pub fn pop_min(&mut self) -> Option<T> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        Some(self.remove_at(0))
+    }
+    pub fn pop_max(&mut self) -> Option<T> {
+        let index = self.max_index()?;
+        Some(self.remove_at(index))
+    }
+
+    fn max_index(&self) -> Option<usize> {
+        match self.heap.len() {
+            0 => None,
+            1 => Some(0),
+            2 => Some(1),
+            _ if self.heap[1] >= self.heap[2] => Some(1),
+            _ => Some(2),
+        }
+    }
+
+    fn remove_at(&mut self, index: usize) -> T {
+        let item = self.heap.swap_remove(index);
+        if index < self.heap.len() {
+            self.trickle_down(index);
+        }
+        item
+    }
+
+    fn bubble_up(&mut self, index: usize) {
+        if index == 0 {
+            return;
+        }
+        let parent = (index - 1) / 2;
+        let min_level = is_min_level(index);
+        if before(&self.heap[parent], &self.heap[index], min_level) {
+            // The element belongs on the parent's kind of level.
+            self.heap.swap(index, parent);
+            self.bubble_up_grandparents(parent, !min_level);
+        } else {
+            self.bubble_up_grandparents(index, min_level);
+        }
+    }
+
+    fn bubble_up_grandparents(&mut self, mut index: usize, min_level: bool) {
+        while index > 2 {
+            let grandparent = (index - 3) / 4;
+            if !before(&self.heap[index], &self.heap[grandparent], min_level) {
+                break;
+            }
+            self.heap.swap(index, grandparent);
+            index = grandparent;
+        }
+    }
+
+    fn trickle_down(&mut self, mut index: usize) {
+        let min_level = is_min_level(index);
+        loop {
+            let first_child = 2 * index + 1;
+            if first_child >= self.heap.len() {
+                return;
+            }
+            // The first among the children and grandchildren.
+            let mut first = first_child;
+            let candidates = [
+                first_child + 1,
+                4 * index + 3,
+                4 * index + 4,
+                4 * index + 5,
+                4 * index + 6,
+            ];
+            for candidate in candidates {
+                if candidate < self.heap.len()
+                    && before(&self.heap[candidate], &self.heap[first], min_level)
+                {
+                    first = candidate;
+                }
+            }
+            if !before(&self.heap[first], &self.heap[index], min_level) {
+                return;
+            }
+            self.heap.swap(first, index);
+            if first <= first_child + 1 {
+                return;
+            }
+            let parent = (first - 1) / 2;
+            if before(&self.heap[parent], &self.heap[first], min_level) {
+                self.heap.swap(first, parent);
+            }
+            index = first;
+        }
+    }
+}
+
+impl<T> Default for MinMaxHeap<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Vec<T>> for MinMaxHeap<T>
+where
+    T: Ord,
+{
+    fn from(heap: Vec<T>) -> Self {
+        let mut heap = Self { heap };
+        for index in (0..heap.len() / 2).rev() {
+            heap.trickle_down(index);
+        }
+        heap
+    }
+}
+
+impl<T> FromIterator<T> for MinMaxHeap<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+fn is_min_level(index: usize) -> bool {
+    (index + 1).ilog2() & 1 == 0
+}
+
+/// Whether `a` should be closer to the root than `b`, on a min or max level.
+fn before<T: Ord>(a: &T, b: &T, min_level: bool) -> bool {
+    if min_level {
+        a < b
+    } else {
+        a > b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::lcg;
+
+    #[test]
+    fn empty() {
+        let mut heap = MinMaxHeap::<u32>::new();
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek_min(), None);
+        assert_eq!(heap.peek_max(), None);
+        assert_eq!(heap.pop_min(), None);
+        assert_eq!(heap.pop_max(), None);
+    }
+
+    #[test]
+    fn single_element() {
+        let mut heap = MinMaxHeap::new();
+        heap.push(7);
+        assert_eq!(heap.peek_min(), Some(&7));
+        assert_eq!(heap.peek_max(), Some(&7));
+        assert_eq!(heap.pop_max(), Some(7));
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn pops_from_both_ends() {
+        let mut heap: MinMaxHeap<_> = [4, 9, 1, 7, 3, 3, 8].into_iter().collect();
+        assert_eq!(heap.pop_min(), Some(1));
+        assert_eq!(heap.pop_max(), Some(9));
+        assert_eq!(heap.pop_max(), Some(8));
+        assert_eq!(heap.pop_min(), Some(3));
+        assert_eq!(heap.pop_min(), Some(3));
+        assert_eq!(heap.pop_max(), Some(7));
+        assert_eq!(heap.pop_max(), Some(4));
+        assert_eq!(heap.pop_min(), None);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut seed = 3;
+        let initial: Vec<u64> = (0..40).map(|_| lcg(&mut seed) % 100).collect();
+        let mut heap = MinMaxHeap::from(initial.clone());
+        let mut expected = initial;
+        expected.sort_unstable();
+        for _ in 0..3000 {
+            match lcg(&mut seed) % 3 {
+                0 => {
+                    let item = lcg(&mut seed) % 100;
+                    heap.push(item);
+                    let index = expected.partition_point(|&x| x < item);
+                    expected.insert(index, item);
+                }
+                1 => {
+                    let min = if expected.is_empty() {
+                        None
+                    } else {
+                        Some(expected.remove(0))
+                    };
+                    assert_eq!(heap.pop_min(), min);
+                }
+                _ => assert_eq!(heap.pop_max(), expected.pop()),
+            }
+            assert_eq!(heap.len(), expected.len());
+            assert_eq!(heap.peek_min(), expected.first());
+            assert_eq!(heap.peek_max(), expected.last());
+        }
+        let mut rest = heap.into_vec();
+        rest.sort_unstable();
+        assert_eq!(rest, expected);
+    }
+}
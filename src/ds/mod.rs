@@ -1,3 +1,27 @@
+mod dary_heap;
+mod disjoint_set;
+mod fenwick;
 mod heap;
+mod heap_by;
+mod indexed_heap;
+mod min_max_heap;
+mod pairing_heap;
+mod percentile;
+mod priority_queue;
+mod radix_heap;
+mod segment_tree;
+mod top_k;
 
+pub use dary_heap::*;
+pub use disjoint_set::*;
+pub use fenwick::*;
 pub use heap::*;
+pub use heap_by::*;
+pub use indexed_heap::*;
+pub use min_max_heap::*;
+pub use pairing_heap::*;
+pub use percentile::*;
+pub use priority_queue::*;
+pub use radix_heap::*;
+pub use segment_tree::*;
+pub use top_k::*;
@@ -0,0 +1,247 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::mem::swap;
+
+use super::{MinPriorityQueue, PriorityQueue};
+
+/// Min heap that can meld with another one in O(1).
+///
+/// Complexity:
+/// Runtime O(1) for `push`, `peek` and `meld`, O(log n) amortized for `pop`.
+/// Space O(n)
+///
+/// # Examples
+///
+/// Merging the queues of two workers:
+///
+/// ```
+/// use kupsy::ds::PairingHeap;
+///
+/// let mut first: PairingHeap<_> = [7, 3, 9].into_iter().collect();
+/// let second: PairingHeap<_> = [4, 1].into_iter().collect();
+/// first.meld(second);
+/// assert_eq!(first.len(), 5);
+/// assert_eq!(first.into_sorted_vec(), vec![1, 3, 4, 7, 9]);
+/// ```
+pub struct PairingHeap<T>
+where
+    T: Ord,
+{
+    root: Option<Box<Node<T>>>,
+    len: usize,
+}
+
+struct Node<T> {
+    item: T,
+    // First child, and the next sibling in the parent's list of children.
+    child: Option<Box<Node<T>>>,
+    sibling: Option<Box<Node<T>>>,
+}
+
+impl<T> PairingHeap<T>
+where
+    T: Ord,
+{
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+    pub fn peek(&self) -> Option<&T> {
+        self.root.as_ref().map(|root| &root.item)
+    }
+    pub fn push(&mut self, item: T) {
+        let node = Box::new(Node {
+            item,
+            child: None,
+            sibling: None,
+        });
+        self.root = Some(match self.root.take() {
+            Some(root) => meld_nodes(root, node),
+            None => node,
+        });
+        self.len += 1;
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        let mut root = self.root.take()?;
+        self.len -= 1;
+
+        let mut children = Vec::new();
+        let mut next = root.child.take();
+        while let Some(mut node) = next {
+            next = node.sibling.take();
+            children.push(node);
+        }
+        // Meld pairs from left to right, then the results from right to left.
+        let mut paired = Vec::with_capacity(children.len().div_ceil(2));
+        let mut children = children.into_iter();
+        while let Some(first) = children.next() {
+            paired.push(match children.next() {
+                Some(second) => meld_nodes(first, second),
+                None => first,
+            });
+        }
+        self.root = paired.into_iter().rev().reduce(meld_nodes);
+
+        Some(root.item)
+    }
+    /// Move every item of `other` into `self`.
+    pub fn meld(&mut self, mut other: Self) {
+        self.len += other.len;
+        other.len = 0;
+        self.root = match (self.root.take(), other.root.take()) {
+            (Some(a), Some(b)) => Some(meld_nodes(a, b)),
+            (a, b) => a.or(b),
+        };
+    }
+    /// Move every item of `other` into `self`, leaving `other` empty.
+    pub fn append(&mut self, other: &mut Self) {
+        let other = core::mem::take(other);
+        self.meld(other);
+    }
+    /// Get the items in ascending order.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut items = Vec::with_capacity(self.len);
+        while let Some(item) = self.pop() {
+            items.push(item);
+        }
+        items
+    }
+}
+
+fn meld_nodes<T: Ord>(mut a: Box<Node<T>>, mut b: Box<Node<T>>) -> Box<Node<T>> {
+    if b.item < a.item {
+        swap(&mut a, &mut b);
+    }
+    b.sibling = a.child.take();
+    a.child = Some(b);
+    a
+}
+
+impl<T> Drop for PairingHeap<T>
+where
+    T: Ord,
+{
+    // The default drop would recurse once per node in a chain of children or siblings.
+    fn drop(&mut self) {
+        let mut stack: Vec<Box<Node<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.child.take());
+            stack.extend(node.sibling.take());
+        }
+    }
+}
+
+impl<T> Default for PairingHeap<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for PairingHeap<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
+
+impl<T> Extend<T> for PairingHeap<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T> PriorityQueue for PairingHeap<T>
+where
+    T: Ord,
+{
+    type Item = T;
+    fn push(&mut self, item: T) {
+        PairingHeap::push(self, item);
+    }
+    fn pop(&mut self) -> Option<T> {
+        PairingHeap::pop(self)
+    }
+    fn peek(&self) -> Option<&T> {
+        PairingHeap::peek(self)
+    }
+    fn len(&self) -> usize {
+        PairingHeap::len(self)
+    }
+}
+
+impl<T> MinPriorityQueue for PairingHeap<T> where T: Ord {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::lcg;
+    use alloc::vec;
+
+    #[test]
+    fn empty() {
+        let mut heap = PairingHeap::<u32>::new();
+        assert_eq!(heap.peek(), None);
+        assert_eq!(heap.pop(), None);
+        heap.meld(PairingHeap::new());
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn meld_and_append() {
+        let mut a: PairingHeap<_> = [5, 2].into_iter().collect();
+        let mut b: PairingHeap<_> = [4, 1, 6].into_iter().collect();
+        a.append(&mut b);
+        assert!(b.is_empty());
+        assert_eq!(a.peek(), Some(&1));
+        a.meld([3].into_iter().collect());
+        assert_eq!(a.into_sorted_vec(), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn matches_sorting() {
+        let mut seed = 41;
+        let mut heap = PairingHeap::new();
+        let mut expected: Vec<u64> = vec![];
+        for _ in 0..3000 {
+            match lcg(&mut seed) % 3 {
+                0 => {
+                    expected.sort_unstable_by(|a, b| b.cmp(a));
+                    assert_eq!(heap.pop(), expected.pop());
+                }
+                _ => {
+                    let item = lcg(&mut seed) % 1000;
+                    heap.push(item);
+                    expected.push(item);
+                }
+            }
+            assert_eq!(heap.len(), expected.len());
+        }
+    }
+
+    #[test]
+    fn drop_long_chain() {
+        // Increasing pushes make one long list of children under the root.
+        let heap: PairingHeap<u32> = (0..200_000).collect();
+        drop(heap);
+    }
+}
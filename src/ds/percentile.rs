@@ -0,0 +1,422 @@
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use super::{MaxHeap, MinHeap};
+
+/// Maintains a percentile of a stream of items, with a <code>[MaxHeap]</code> of the items at or
+/// below the percentile and a <code>[MinHeap]</code> of the ones above it.
+///
+/// The percentile is `numerator / denominator`, and uses the nearest rank: out of n items, it's
+/// the `ceil(n * numerator / denominator)`-th smallest one, or the smallest one if that's 0.
+///
+/// Complexity:
+/// Runtime O(log n) for `insert`, O(1) for `percentile`.
+/// Space O(n)
+///
+/// # Examples
+///
+/// The 90th percentile of [Interval](crate::intervals::Interval) lengths:
+///
+/// ```
+/// use kupsy::ds::RunningPercentile;
+///
+/// let mut p90 = RunningPercentile::new(9, 10);
+/// for length in 1..=20 {
+///     p90.insert(length);
+/// }
+/// assert_eq!(p90.percentile(), Some(&18));
+/// ```
+pub struct RunningPercentile<T>
+where
+    T: Ord,
+{
+    numerator: usize,
+    denominator: usize,
+    lower: MaxHeap<T>,
+    upper: MinHeap<T>,
+}
+
+impl<T> RunningPercentile<T>
+where
+    T: Ord,
+{
+    /// Panics if `denominator` is 0, or `numerator` is larger than it.
+    pub fn new(numerator: usize, denominator: usize) -> Self {
+        assert_percentile(numerator, denominator);
+        Self {
+            numerator,
+            denominator,
+            lower: MaxHeap::new(),
+            upper: MinHeap::new(),
+        }
+    }
+    /// The 50th percentile, which is the lower median for an even number of items.
+    pub fn median() -> Self {
+        Self::new(1, 2)
+    }
+    pub fn len(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn insert(&mut self, item: T) {
+        if self.lower.peek().is_some_and(|top| item > *top) {
+            self.upper.push(item);
+        } else {
+            self.lower.push(item);
+        }
+        let target = lower_len(self.len(), self.numerator, self.denominator);
+        while self.lower.len() > target {
+            self.upper.push(self.lower.pop().unwrap());
+        }
+        while self.lower.len() < target {
+            self.lower.push(self.upper.pop().unwrap());
+        }
+    }
+    pub fn percentile(&self) -> Option<&T> {
+        self.lower.peek()
+    }
+}
+
+impl<T> Extend<T> for RunningPercentile<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+/// Maintains the median of a stream of items.
+///
+/// For an even number of items, the lower median is returned, since `T` can't be averaged in
+/// general.
+///
+/// Complexity:
+/// Runtime O(log n) for `insert`, O(1) for `median`.
+/// Space O(n)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::RunningMedian;
+///
+/// let mut median = RunningMedian::new();
+/// median.extend([5, 1, 9]);
+/// assert_eq!(median.median(), Some(&5));
+/// median.insert(2);
+/// assert_eq!(median.median(), Some(&2));
+/// ```
+pub struct RunningMedian<T>
+where
+    T: Ord,
+{
+    inner: RunningPercentile<T>,
+}
+
+impl<T> RunningMedian<T>
+where
+    T: Ord,
+{
+    pub fn new() -> Self {
+        Self {
+            inner: RunningPercentile::median(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    pub fn insert(&mut self, item: T) {
+        self.inner.insert(item);
+    }
+    pub fn median(&self) -> Option<&T> {
+        self.inner.percentile()
+    }
+}
+
+impl<T> Default for RunningMedian<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for RunningMedian<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
+}
+
+/// <code>[RunningPercentile]</code> that also supports removing items, for sliding windows.
+///
+/// Removed items are only counted as removed, and leave the heaps once they reach a top.
+///
+/// Complexity:
+/// Runtime O(log n) amortized for `insert` and `remove`, O(1) for `percentile`.
+/// Space O(n + r)
+/// Where r = number of removed items still in the heaps.
+///
+/// # Examples
+///
+/// Median over a window of 3:
+///
+/// ```
+/// use kupsy::ds::SlidingPercentile;
+///
+/// let lengths = [4, 9, 1, 7, 3];
+/// let mut window = SlidingPercentile::median();
+/// let mut medians = vec![];
+/// for (i, &length) in lengths.iter().enumerate() {
+///     window.insert(length);
+///     if i >= 3 {
+///         window.remove(&lengths[i - 3]);
+///     }
+///     if i >= 2 {
+///         medians.push(*window.percentile().unwrap());
+///     }
+/// }
+/// assert_eq!(medians, vec![4, 7, 3]);
+/// ```
+pub struct SlidingPercentile<T>
+where
+    T: Ord + Hash + Clone,
+{
+    numerator: usize,
+    denominator: usize,
+    lower: MaxHeap<T>,
+    upper: MinHeap<T>,
+    // Sizes without the removed items.
+    lower_len: usize,
+    upper_len: usize,
+    removed_lower: HashMap<T, usize>,
+    removed_upper: HashMap<T, usize>,
+    counts: HashMap<T, usize>,
+}
+
+impl<T> SlidingPercentile<T>
+where
+    T: Ord + Hash + Clone,
+{
+    /// Panics if `denominator` is 0, or `numerator` is larger than it.
+    pub fn new(numerator: usize, denominator: usize) -> Self {
+        assert_percentile(numerator, denominator);
+        Self {
+            numerator,
+            denominator,
+            lower: MaxHeap::new(),
+            upper: MinHeap::new(),
+            lower_len: 0,
+            upper_len: 0,
+            removed_lower: HashMap::new(),
+            removed_upper: HashMap::new(),
+            counts: HashMap::new(),
+        }
+    }
+    /// The 50th percentile, which is the lower median for an even number of items.
+    pub fn median() -> Self {
+        Self::new(1, 2)
+    }
+    pub fn len(&self) -> usize {
+        self.lower_len + self.upper_len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn insert(&mut self, item: T) {
+        *self.counts.entry(item.clone()).or_insert(0) += 1;
+        if self.lower.peek().is_some_and(|top| item > *top) {
+            self.upper.push(item);
+            self.upper_len += 1;
+        } else {
+            self.lower.push(item);
+            self.lower_len += 1;
+        }
+        self.rebalance();
+    }
+    /// Remove one copy of `item`.
+    ///
+    /// Returns `false`, and does nothing, if `item` isn't there.
+    pub fn remove(&mut self, item: &T) -> bool {
+        if !take_one(&mut self.counts, item) {
+            return false;
+        }
+        // Every item in `lower` is smaller than, or equal to every item in `upper`, so if `item`
+        // isn't above the top of `lower`, `lower` has a copy of it.
+        if self.lower.peek().is_some_and(|top| item <= top) {
+            *self.removed_lower.entry(item.clone()).or_insert(0) += 1;
+            self.lower_len -= 1;
+            self.prune_lower();
+        } else {
+            *self.removed_upper.entry(item.clone()).or_insert(0) += 1;
+            self.upper_len -= 1;
+            self.prune_upper();
+        }
+        self.rebalance();
+        true
+    }
+    pub fn percentile(&self) -> Option<&T> {
+        self.lower.peek()
+    }
+
+    fn rebalance(&mut self) {
+        let target = lower_len(self.len(), self.numerator, self.denominator);
+        while self.lower_len > target {
+            let item = self.lower.pop().unwrap();
+            self.lower_len -= 1;
+            self.prune_lower();
+            self.upper.push(item);
+            self.upper_len += 1;
+        }
+        while self.lower_len < target {
+            let item = self.upper.pop().unwrap();
+            self.upper_len -= 1;
+            self.prune_upper();
+            self.lower.push(item);
+            self.lower_len += 1;
+        }
+    }
+
+    fn prune_lower(&mut self) {
+        while let Some(top) = self.lower.peek() {
+            if !take_one(&mut self.removed_lower, top) {
+                break;
+            }
+            self.lower.pop();
+        }
+    }
+
+    fn prune_upper(&mut self) {
+        while let Some(top) = self.upper.peek() {
+            if !take_one(&mut self.removed_upper, top) {
+                break;
+            }
+            self.upper.pop();
+        }
+    }
+}
+
+fn assert_percentile(numerator: usize, denominator: usize) {
+    assert!(
+        denominator > 0 && numerator <= denominator,
+        "percentile must be between 0 and 1"
+    );
+}
+
+/// Number of items at or below the percentile, out of `len`.
+fn lower_len(len: usize, numerator: usize, denominator: usize) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    let rank = (len as u128 * numerator as u128).div_ceil(denominator as u128) as usize;
+    rank.max(1)
+}
+
+/// Decrement the count of `item`, if it's there.
+fn take_one<T: Hash + Eq>(counts: &mut HashMap<T, usize>, item: &T) -> bool {
+    let Some(count) = counts.get_mut(item) else {
+        return false;
+    };
+    *count -= 1;
+    if *count == 0 {
+        counts.remove(item);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::lcg;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn nearest_rank(sorted: &[u64], numerator: usize, denominator: usize) -> Option<&u64> {
+        let rank = lower_len(sorted.len(), numerator, denominator);
+        rank.checked_sub(1).map(|index| &sorted[index])
+    }
+
+    #[test]
+    fn empty() {
+        let median = RunningMedian::<u32>::new();
+        assert!(median.is_empty());
+        assert_eq!(median.median(), None);
+        let mut window = SlidingPercentile::<u32>::median();
+        assert_eq!(window.percentile(), None);
+        assert!(!window.remove(&3));
+    }
+
+    #[test]
+    fn extremes() {
+        let mut min = RunningPercentile::new(0, 1);
+        let mut max = RunningPercentile::new(1, 1);
+        min.extend([4, 2, 8]);
+        max.extend([4, 2, 8]);
+        assert_eq!(min.percentile(), Some(&2));
+        assert_eq!(max.percentile(), Some(&8));
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_percentile() {
+        RunningPercentile::<u32>::new(3, 2);
+    }
+
+    #[test]
+    fn running_matches_sorting() {
+        for (numerator, denominator) in [(1, 2), (1, 4), (9, 10), (99, 100), (0, 3)] {
+            let mut seed = 23;
+            let mut running = RunningPercentile::new(numerator, denominator);
+            let mut sorted: Vec<u64> = vec![];
+            for _ in 0..300 {
+                let item = lcg(&mut seed) % 50;
+                running.insert(item);
+                let index = sorted.partition_point(|&x| x < item);
+                sorted.insert(index, item);
+                assert_eq!(
+                    running.percentile(),
+                    nearest_rank(&sorted, numerator, denominator)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sliding_matches_sorting() {
+        for (numerator, denominator) in [(1, 2), (1, 10), (3, 4)] {
+            let mut seed = 29;
+            let mut window = SlidingPercentile::new(numerator, denominator);
+            let mut sorted: Vec<u64> = vec![];
+            for _ in 0..2000 {
+                let item = lcg(&mut seed) % 20;
+                if lcg(&mut seed) % 5 < 2 {
+                    let index = sorted.binary_search(&item);
+                    assert_eq!(window.remove(&item), index.is_ok());
+                    if let Ok(index) = index {
+                        sorted.remove(index);
+                    }
+                } else {
+                    window.insert(item);
+                    let index = sorted.partition_point(|&x| x < item);
+                    sorted.insert(index, item);
+                }
+                assert_eq!(window.len(), sorted.len());
+                assert_eq!(
+                    window.percentile(),
+                    nearest_rank(&sorted, numerator, denominator)
+                );
+            }
+        }
+    }
+}
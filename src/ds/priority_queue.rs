@@ -0,0 +1,138 @@
+use super::{HeapBy, HeapOrder, MaxHeap, MinHeap};
+
+/// Common interface of the heaps, so algorithms can be written once for any of them.
+///
+/// Which item comes first depends on the queue. Algorithms that need the smallest one first
+/// should take a <code>[MinPriorityQueue]</code>.
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::{DaryHeap, MinHeap, PairingHeap, PriorityQueue};
+///
+/// fn drain_sorted<Q: PriorityQueue<Item = u32>>(mut queue: Q) -> Vec<u32> {
+///     queue.push(3);
+///     queue.push(1);
+///     queue.push(2);
+///     core::iter::from_fn(|| queue.pop()).collect()
+/// }
+///
+/// assert_eq!(drain_sorted(MinHeap::new()), vec![1, 2, 3]);
+/// assert_eq!(drain_sorted(DaryHeap::<_, 4>::new()), vec![1, 2, 3]);
+/// assert_eq!(drain_sorted(PairingHeap::new()), vec![1, 2, 3]);
+/// ```
+pub trait PriorityQueue {
+    type Item;
+
+    fn push(&mut self, item: Self::Item);
+    /// Remove the first item, and return it.
+    fn pop(&mut self) -> Option<Self::Item>;
+    /// Get the item that <code>[pop](Self::pop)</code> would return.
+    fn peek(&self) -> Option<&Self::Item>;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// <code>[PriorityQueue]</code> that pops the smallest item first, by its [Ord].
+///
+/// Not implemented by <code>[MaxHeap]</code>, or by <code>[HeapBy]</code>, whose order can be
+/// anything.
+///
+/// # Examples
+///
+/// ```compile_fail
+/// use kupsy::ds::{MaxHeap, MinPriorityQueue};
+///
+/// fn smallest_first<Q: MinPriorityQueue>(queue: Q) {}
+///
+/// smallest_first(MaxHeap::<u32>::new());
+/// ```
+pub trait MinPriorityQueue: PriorityQueue {}
+
+impl<T> PriorityQueue for MinHeap<T>
+where
+    T: Ord,
+{
+    type Item = T;
+    fn push(&mut self, item: T) {
+        MinHeap::push(self, item);
+    }
+    fn pop(&mut self) -> Option<T> {
+        MinHeap::pop(self)
+    }
+    fn peek(&self) -> Option<&T> {
+        MinHeap::peek(self)
+    }
+    fn len(&self) -> usize {
+        MinHeap::len(self)
+    }
+}
+
+impl<T> MinPriorityQueue for MinHeap<T> where T: Ord {}
+
+impl<T> PriorityQueue for MaxHeap<T>
+where
+    T: Ord,
+{
+    type Item = T;
+    fn push(&mut self, item: T) {
+        MaxHeap::push(self, item);
+    }
+    fn pop(&mut self) -> Option<T> {
+        MaxHeap::pop(self)
+    }
+    fn peek(&self) -> Option<&T> {
+        MaxHeap::peek(self)
+    }
+    fn len(&self) -> usize {
+        MaxHeap::len(self)
+    }
+}
+
+impl<T, O> PriorityQueue for HeapBy<T, O>
+where
+    O: HeapOrder<T>,
+{
+    type Item = T;
+    fn push(&mut self, item: T) {
+        HeapBy::push(self, item);
+    }
+    fn pop(&mut self) -> Option<T> {
+        HeapBy::pop(self)
+    }
+    fn peek(&self) -> Option<&T> {
+        HeapBy::peek(self)
+    }
+    fn len(&self) -> usize {
+        HeapBy::len(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ds::{DaryHeap, PairingHeap};
+    use crate::testing::lcg;
+    use alloc::vec::Vec;
+
+    fn pops_smallest_first<Q: MinPriorityQueue<Item = u32>>(mut queue: Q) {
+        let mut seed = 89;
+        let mut items: Vec<u32> = (0..200).map(|_| lcg(&mut seed) as u32 % 50).collect();
+        for &item in &items {
+            queue.push(item);
+        }
+        items.sort_unstable();
+        let popped: Vec<u32> = core::iter::from_fn(|| queue.pop()).collect();
+        assert_eq!(popped, items);
+    }
+
+    #[test]
+    fn min_queues_pop_smallest_first() {
+        pops_smallest_first(MinHeap::new());
+        pops_smallest_first(DaryHeap::<_, 2>::new());
+        pops_smallest_first(DaryHeap::<_, 8>::new());
+        pops_smallest_first(PairingHeap::new());
+    }
+}
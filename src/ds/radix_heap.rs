@@ -0,0 +1,336 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem::take;
+
+use super::PriorityQueue;
+
+/// Unsigned integer that can be used as a <code>[RadixHeap]</code> key.
+pub trait RadixKey: Copy + Ord {
+    const BITS: u32;
+    const MIN: Self;
+    /// Number of significant bits of `self ^ other`.
+    fn radix_distance(self, other: Self) -> u32;
+}
+
+macro_rules! impl_radix_key {
+    ($($t:ty),*) => {
+        $(
+            impl RadixKey for $t {
+                const BITS: u32 = <$t>::BITS;
+                const MIN: Self = 0;
+                fn radix_distance(self, other: Self) -> u32 {
+                    <$t>::BITS - (self ^ other).leading_zeros()
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_key!(u8, u16, u32, u64, u128, usize);
+
+/// Monotone min heap for unsigned integer keys, where a pushed key is never smaller than the last
+/// popped one, as in Dijkstra's algorithm or sweep lines over timestamps.
+///
+/// Items are kept in buckets by the highest bit where their key differs from the last popped key.
+/// Popping from an empty lowest bucket moves the items of the next bucket into lower ones, and
+/// every item can only move down at most `K::BITS` times.
+///
+/// Complexity:
+/// Runtime O(1) for `push` and `peek`, O(log C) amortized for `pop`.
+/// Space O(n + log C)
+/// Where C = largest key - smallest key.
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::RadixHeap;
+///
+/// let mut heap = RadixHeap::new();
+/// heap.push(10_u32, "b");
+/// heap.push(3, "a");
+/// assert_eq!(heap.pop(), Some((3, "a")));
+/// heap.push(7, "c");
+/// assert!(heap.try_push(2, "too early").is_err());
+/// assert_eq!(heap.pop(), Some((7, "c")));
+/// assert_eq!(heap.pop(), Some((10, "b")));
+/// ```
+#[derive(Clone)]
+pub struct RadixHeap<K, V>
+where
+    K: RadixKey,
+{
+    // buckets[i] holds the items whose key has a radix distance of i from `last`.
+    buckets: Vec<Vec<(K, V)>>,
+    // bucket_min[i] is the index of the last smallest item in buckets[i], for i > 0 if it isn't
+    // empty, which is the one pop takes after moving the bucket down.
+    bucket_min: Vec<usize>,
+    // Index of the lowest non-empty bucket, if there are any items.
+    lowest: usize,
+    last: K,
+    len: usize,
+}
+
+impl<K, V> RadixHeap<K, V>
+where
+    K: RadixKey,
+{
+    pub fn new() -> Self {
+        Self {
+            buckets: (0..=K::BITS).map(|_| Vec::new()).collect(),
+            bucket_min: (0..=K::BITS).map(|_| 0).collect(),
+            lowest: 0,
+            last: K::MIN,
+            len: 0,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Remove every item, and allow any key to be pushed again.
+    pub fn clear(&mut self) {
+        for bucket in &mut self.buckets {
+            bucket.clear();
+        }
+        self.lowest = 0;
+        self.last = K::MIN;
+        self.len = 0;
+    }
+    /// The last popped key, which is the smallest key that can be pushed.
+    pub fn last_key(&self) -> K {
+        self.last
+    }
+    /// Panics if `key` is smaller than the last popped key.
+    pub fn push(&mut self, key: K, value: V) {
+        assert!(key >= self.last, "key is smaller than the last popped key");
+        self.push_unchecked(key, value);
+    }
+    /// Push `key` and `value`, or give them back if `key` is smaller than the last popped key.
+    pub fn try_push(&mut self, key: K, value: V) -> Result<(), (K, V)> {
+        if key < self.last {
+            return Err((key, value));
+        }
+        self.push_unchecked(key, value);
+        Ok(())
+    }
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        if self.len == 0 {
+            return None;
+        }
+        if self.lowest > 0 {
+            let index = self.lowest;
+            let mut bucket = take(&mut self.buckets[index]);
+            self.last = bucket[self.bucket_min[index]].0;
+            // Every key shares the bits above `index` with the new `last`, so all of them move to
+            // a lower bucket, and the smallest one to bucket 0.
+            for (key, value) in bucket.drain(..) {
+                self.place(key, value);
+            }
+            self.buckets[index] = bucket;
+            self.lowest = 0;
+        }
+        self.len -= 1;
+        let item = self.buckets[0].pop();
+        if self.buckets[0].is_empty() && self.len > 0 {
+            self.lowest = self
+                .buckets
+                .iter()
+                .position(|bucket| !bucket.is_empty())
+                .unwrap();
+        }
+        item
+    }
+    /// Get the item <code>[pop](Self::pop)</code> would return.
+    pub fn peek(&self) -> Option<&(K, V)> {
+        if self.len == 0 {
+            return None;
+        }
+        let bucket = &self.buckets[self.lowest];
+        if self.lowest == 0 {
+            // Every key in bucket 0 is `last`, and pop takes the last item.
+            bucket.last()
+        } else {
+            bucket.get(self.bucket_min[self.lowest])
+        }
+    }
+
+    fn push_unchecked(&mut self, key: K, value: V) {
+        let index = self.place(key, value);
+        if self.len == 0 || index < self.lowest {
+            self.lowest = index;
+        }
+        self.len += 1;
+    }
+
+    /// Put an item in its bucket, and return the bucket's index.
+    fn place(&mut self, key: K, value: V) -> usize {
+        let index = key.radix_distance(self.last) as usize;
+        let bucket = &mut self.buckets[index];
+        if index > 0 && (bucket.is_empty() || key <= bucket[self.bucket_min[index]].0) {
+            self.bucket_min[index] = bucket.len();
+        }
+        bucket.push((key, value));
+        index
+    }
+}
+
+impl<K, V> Default for RadixHeap<K, V>
+where
+    K: RadixKey,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> fmt::Debug for RadixHeap<K, V>
+where
+    K: RadixKey + fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.buckets.iter().flatten())
+            .finish()
+    }
+}
+
+/// Panics if a key is smaller than the last popped key.
+impl<K, V> Extend<(K, V)> for RadixHeap<K, V>
+where
+    K: RadixKey,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.push(key, value);
+        }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for RadixHeap<K, V>
+where
+    K: RadixKey,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
+
+/// `push` panics if the key is smaller than the last popped key.
+impl<K, V> PriorityQueue for RadixHeap<K, V>
+where
+    K: RadixKey,
+{
+    type Item = (K, V);
+    fn push(&mut self, (key, value): (K, V)) {
+        RadixHeap::push(self, key, value);
+    }
+    fn pop(&mut self) -> Option<(K, V)> {
+        RadixHeap::pop(self)
+    }
+    fn peek(&self) -> Option<&(K, V)> {
+        RadixHeap::peek(self)
+    }
+    fn len(&self) -> usize {
+        RadixHeap::len(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ds::MinHeap;
+    use crate::testing::lcg;
+
+    #[test]
+    fn empty() {
+        let mut heap = RadixHeap::<u32, ()>::new();
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek(), None);
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn extreme_keys() {
+        let mut heap: RadixHeap<u8, char> =
+            [(255, 'b'), (0, 'a'), (255, 'c')].into_iter().collect();
+        assert_eq!(heap.pop(), Some((0, 'a')));
+        assert_eq!(heap.pop().map(|(key, _)| key), Some(255));
+        assert_eq!(heap.last_key(), 255);
+        heap.push(255, 'd');
+        assert_eq!(heap.len(), 2);
+    }
+
+    #[test]
+    fn rejects_keys_below_last_popped() {
+        let mut heap = RadixHeap::new();
+        heap.push(5_u64, 'a');
+        heap.push(9, 'b');
+        heap.pop();
+        assert_eq!(heap.try_push(4, 'c'), Err((4, 'c')));
+        assert_eq!(heap.try_push(5, 'd'), Ok(()));
+        heap.clear();
+        assert_eq!(heap.try_push(0, 'e'), Ok(()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_below_last_popped_panics() {
+        let mut heap = RadixHeap::new();
+        heap.push(5_u16, ());
+        heap.pop();
+        heap.push(4, ());
+    }
+
+    #[test]
+    fn peek_matches_pop() {
+        let mut seed = 47;
+        let mut heap = RadixHeap::<u32, u32>::new();
+        for i in 0..2000_u32 {
+            match lcg(&mut seed) % 3 {
+                0 => {
+                    let peeked = heap.peek().copied();
+                    assert_eq!(heap.pop(), peeked);
+                }
+                _ => {
+                    let key = heap.last_key() + lcg(&mut seed) as u32 % 40;
+                    heap.push(key, i);
+                }
+            }
+        }
+        while let Some(&peeked) = heap.peek() {
+            assert_eq!(heap.pop(), Some(peeked));
+        }
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn matches_min_heap() {
+        let mut seed = 43;
+        let mut radix = RadixHeap::new();
+        let mut expected = MinHeap::new();
+        let mut last = 0_u64;
+        for i in 0..5000_u64 {
+            match lcg(&mut seed) % 3 {
+                0 => {
+                    let popped = radix.pop();
+                    assert_eq!(popped.map(|(key, _)| key), expected.pop());
+                    if let Some((key, _)) = popped {
+                        last = key;
+                    }
+                }
+                _ => {
+                    let key = last + lcg(&mut seed) % 1000;
+                    radix.push(key, i);
+                    expected.push(key);
+                }
+            }
+            assert_eq!(radix.len(), expected.len());
+            assert_eq!(radix.peek().map(|(key, _)| key), expected.peek());
+        }
+    }
+}
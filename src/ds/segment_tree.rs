@@ -0,0 +1,641 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+use core::marker::PhantomData;
+use core::ops::{Add, Bound, Mul, RangeBounds};
+
+/// An associative operation with an identity element.
+///
+/// `combine(identity(), a) == a`, `combine(a, identity()) == a`, and
+/// `combine(combine(a, b), c) == combine(a, combine(b, c))`.
+pub trait Monoid {
+    type Value: Clone;
+
+    fn identity() -> Self::Value;
+    fn combine(a: &Self::Value, b: &Self::Value) -> Self::Value;
+}
+
+/// An update that can be applied to a whole range of a <code>[LazySegmentTree]</code> at once.
+pub trait Action<M>: Clone
+where
+    M: Monoid,
+{
+    /// Apply to `value`, which is the combination of `len` elements.
+    fn apply(&self, value: &M::Value, len: usize) -> M::Value;
+    /// Get the action that is the same as applying `older`, and then `self`.
+    fn compose(&self, older: &Self) -> Self;
+}
+
+/// Primitive integers, for the built in <code>[Monoid]</code>s and <code>[Action]</code>s.
+pub trait Numeric: Copy + Ord + Add<Output = Self> + Mul<Output = Self> {
+    const ZERO: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn from_usize(n: usize) -> Self;
+}
+
+macro_rules! impl_numeric {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                const ZERO: Self = 0;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn from_usize(n: usize) -> Self {
+                    n as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Range sum.
+pub struct Sum<T>(PhantomData<T>);
+
+/// Range minimum.
+pub struct Min<T>(PhantomData<T>);
+
+/// Range maximum.
+pub struct Max<T>(PhantomData<T>);
+
+impl<T: Numeric> Monoid for Sum<T> {
+    type Value = T;
+
+    fn identity() -> T {
+        T::ZERO
+    }
+    fn combine(a: &T, b: &T) -> T {
+        *a + *b
+    }
+}
+
+impl<T: Numeric> Monoid for Min<T> {
+    type Value = T;
+
+    fn identity() -> T {
+        T::MAX
+    }
+    fn combine(a: &T, b: &T) -> T {
+        min(*a, *b)
+    }
+}
+
+impl<T: Numeric> Monoid for Max<T> {
+    type Value = T;
+
+    fn identity() -> T {
+        T::MIN
+    }
+    fn combine(a: &T, b: &T) -> T {
+        max(*a, *b)
+    }
+}
+
+/// Add a value to every element in a range.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RangeAdd<T>(pub T);
+
+/// Set every element in a range to a value.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RangeAssign<T>(pub T);
+
+impl<T: Numeric> Action<Sum<T>> for RangeAdd<T> {
+    fn apply(&self, value: &T, len: usize) -> T {
+        *value + self.0 * T::from_usize(len)
+    }
+    fn compose(&self, older: &Self) -> Self {
+        Self(older.0 + self.0)
+    }
+}
+
+impl<T: Numeric> Action<Min<T>> for RangeAdd<T> {
+    fn apply(&self, value: &T, _len: usize) -> T {
+        // The identity stands for no elements, like the leaves of a new tree, so it stays as is.
+        if *value == T::MAX {
+            *value
+        } else {
+            *value + self.0
+        }
+    }
+    fn compose(&self, older: &Self) -> Self {
+        Self(older.0 + self.0)
+    }
+}
+
+impl<T: Numeric> Action<Max<T>> for RangeAdd<T> {
+    fn apply(&self, value: &T, _len: usize) -> T {
+        // The identity stands for no elements, like the leaves of a new tree, so it stays as is.
+        if *value == T::MIN {
+            *value
+        } else {
+            *value + self.0
+        }
+    }
+    fn compose(&self, older: &Self) -> Self {
+        Self(older.0 + self.0)
+    }
+}
+
+impl<T: Numeric> Action<Sum<T>> for RangeAssign<T> {
+    fn apply(&self, _value: &T, len: usize) -> T {
+        self.0 * T::from_usize(len)
+    }
+    fn compose(&self, _older: &Self) -> Self {
+        *self
+    }
+}
+
+impl<T: Numeric> Action<Min<T>> for RangeAssign<T> {
+    fn apply(&self, _value: &T, _len: usize) -> T {
+        self.0
+    }
+    fn compose(&self, _older: &Self) -> Self {
+        *self
+    }
+}
+
+impl<T: Numeric> Action<Max<T>> for RangeAssign<T> {
+    fn apply(&self, _value: &T, _len: usize) -> T {
+        self.0
+    }
+    fn compose(&self, _older: &Self) -> Self {
+        *self
+    }
+}
+
+/// Segment tree over a <code>[Monoid]</code>, with point updates and range queries.
+///
+/// Complexity:
+/// Runtime O(log n) for `set` and `query`, O(n) for building.
+/// Space O(n)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::{SegmentTree, Sum};
+///
+/// let mut tree = SegmentTree::<Sum<i32>>::from(vec![1, 2, 3, 4]);
+/// assert_eq!(tree.query(1..3), 5);
+/// tree.set(1, 10);
+/// assert_eq!(tree.query(..), 18);
+/// ```
+pub struct SegmentTree<M>
+where
+    M: Monoid,
+{
+    len: usize,
+    tree: Vec<M::Value>,
+}
+
+impl<M> SegmentTree<M>
+where
+    M: Monoid,
+{
+    /// Segment tree with `len` elements, all set to the identity.
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            tree: vec![M::identity(); 2 * len],
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn get(&self, index: usize) -> &M::Value {
+        assert!(index < self.len, "index out of bounds");
+        &self.tree[self.len + index]
+    }
+    pub fn set(&mut self, index: usize, value: M::Value) {
+        assert!(index < self.len, "index out of bounds");
+        let mut node = self.len + index;
+        self.tree[node] = value;
+        while node > 1 {
+            node /= 2;
+            self.tree[node] = M::combine(&self.tree[2 * node], &self.tree[2 * node + 1]);
+        }
+    }
+    /// Combine every element in `range`, in order.
+    pub fn query<R>(&self, range: R) -> M::Value
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = to_bounds(range, self.len);
+        let mut left = start + self.len;
+        let mut right = end + self.len;
+        let mut left_acc = M::identity();
+        let mut right_acc = M::identity();
+        while left < right {
+            if left % 2 == 1 {
+                left_acc = M::combine(&left_acc, &self.tree[left]);
+                left += 1;
+            }
+            if right % 2 == 1 {
+                right -= 1;
+                right_acc = M::combine(&self.tree[right], &right_acc);
+            }
+            left /= 2;
+            right /= 2;
+        }
+        M::combine(&left_acc, &right_acc)
+    }
+}
+
+impl<M> From<Vec<M::Value>> for SegmentTree<M>
+where
+    M: Monoid,
+{
+    fn from(values: Vec<M::Value>) -> Self {
+        let len = values.len();
+        let mut tree = vec![M::identity(); len];
+        tree.extend(values);
+        for node in (1..len).rev() {
+            tree[node] = M::combine(&tree[2 * node], &tree[2 * node + 1]);
+        }
+        Self { len, tree }
+    }
+}
+
+/// Segment tree over a <code>[Monoid]</code>, with range updates through lazy propagation.
+///
+/// Complexity:
+/// Runtime O(log n) for `set`, `update` and `query`, O(n) for building.
+/// Space O(n)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::{LazySegmentTree, Min, RangeAdd};
+///
+/// let mut tree = LazySegmentTree::<Min<i32>, RangeAdd<i32>>::from(vec![5, 2, 6, 3]);
+/// tree.update(0..2, RangeAdd(4));
+/// assert_eq!(tree.query(..), 3);
+/// assert_eq!(tree.query(..2), 6);
+/// ```
+pub struct LazySegmentTree<M, A>
+where
+    M: Monoid,
+    A: Action<M>,
+{
+    len: usize,
+    values: Vec<M::Value>,
+    lazy: Vec<Option<A>>,
+}
+
+impl<M, A> LazySegmentTree<M, A>
+where
+    M: Monoid,
+    A: Action<M>,
+{
+    /// Segment tree with `len` elements, all set to the identity.
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            values: vec![M::identity(); 4 * len],
+            lazy: vec![None; 4 * len],
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn get(&self, index: usize) -> M::Value {
+        assert!(index < self.len, "index out of bounds");
+        self.query(index..=index)
+    }
+    pub fn set(&mut self, index: usize, value: M::Value) {
+        assert!(index < self.len, "index out of bounds");
+        self.set_node(1, 0, self.len, index, value);
+    }
+    /// Apply `action` to every element in `range`.
+    pub fn update<R>(&mut self, range: R, action: A)
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = to_bounds(range, self.len);
+        if start < end {
+            self.update_node(1, 0, self.len, start, end, &action);
+        }
+    }
+    /// Combine every element in `range`, in order.
+    pub fn query<R>(&self, range: R) -> M::Value
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = to_bounds(range, self.len);
+        if start < end {
+            self.query_node(1, 0, self.len, start, end)
+        } else {
+            M::identity()
+        }
+    }
+
+    fn build(&mut self, node: usize, left: usize, right: usize, values: &mut Vec<M::Value>) {
+        if right - left == 1 {
+            // Leaves are built right to left, so the next value is always the last one.
+            self.values[node] = values.pop().unwrap();
+            return;
+        }
+        let middle = left + (right - left) / 2;
+        self.build(2 * node + 1, middle, right, values);
+        self.build(2 * node, left, middle, values);
+        self.pull(node);
+    }
+
+    fn pull(&mut self, node: usize) {
+        self.values[node] = M::combine(&self.values[2 * node], &self.values[2 * node + 1]);
+    }
+
+    fn apply_node(&mut self, node: usize, len: usize, action: &A) {
+        self.values[node] = action.apply(&self.values[node], len);
+        self.lazy[node] = Some(match self.lazy[node].take() {
+            Some(older) => action.compose(&older),
+            None => action.clone(),
+        });
+    }
+
+    fn push(&mut self, node: usize, left: usize, right: usize) {
+        if let Some(action) = self.lazy[node].take() {
+            let middle = left + (right - left) / 2;
+            self.apply_node(2 * node, middle - left, &action);
+            self.apply_node(2 * node + 1, right - middle, &action);
+        }
+    }
+
+    fn set_node(&mut self, node: usize, left: usize, right: usize, index: usize, value: M::Value) {
+        if right - left == 1 {
+            self.values[node] = value;
+            self.lazy[node] = None;
+            return;
+        }
+        self.push(node, left, right);
+        let middle = left + (right - left) / 2;
+        if index < middle {
+            self.set_node(2 * node, left, middle, index, value);
+        } else {
+            self.set_node(2 * node + 1, middle, right, index, value);
+        }
+        self.pull(node);
+    }
+
+    fn update_node(
+        &mut self,
+        node: usize,
+        left: usize,
+        right: usize,
+        start: usize,
+        end: usize,
+        action: &A,
+    ) {
+        if end <= left || right <= start {
+            return;
+        }
+        if start <= left && right <= end {
+            self.apply_node(node, right - left, action);
+            return;
+        }
+        self.push(node, left, right);
+        let middle = left + (right - left) / 2;
+        self.update_node(2 * node, left, middle, start, end, action);
+        self.update_node(2 * node + 1, middle, right, start, end, action);
+        self.pull(node);
+    }
+
+    fn query_node(
+        &self,
+        node: usize,
+        left: usize,
+        right: usize,
+        start: usize,
+        end: usize,
+    ) -> M::Value {
+        if end <= left || right <= start {
+            return M::identity();
+        }
+        if start <= left && right <= end {
+            return self.values[node].clone();
+        }
+        let middle = left + (right - left) / 2;
+        let value = M::combine(
+            &self.query_node(2 * node, left, middle, start, end),
+            &self.query_node(2 * node + 1, middle, right, start, end),
+        );
+        // Pending actions are applied to the part of the range that is inside this node, instead
+        // of being pushed down, so that querying doesn't need `&mut self`.
+        match &self.lazy[node] {
+            Some(action) => action.apply(&value, min(right, end) - max(left, start)),
+            None => value,
+        }
+    }
+}
+
+impl<M, A> From<Vec<M::Value>> for LazySegmentTree<M, A>
+where
+    M: Monoid,
+    A: Action<M>,
+{
+    fn from(mut values: Vec<M::Value>) -> Self {
+        let len = values.len();
+        let mut tree = Self::new(len);
+        if len > 0 {
+            tree.build(1, 0, len, &mut values);
+        }
+        tree
+    }
+}
+
+pub(crate) fn to_bounds<R>(range: R, len: usize) -> (usize, usize)
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end && end <= len, "range out of bounds");
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::lcg;
+
+    #[test]
+    fn empty() {
+        let tree = SegmentTree::<Sum<i32>>::new(0);
+        assert!(tree.is_empty());
+        assert_eq!(tree.query(..), 0);
+
+        let tree = LazySegmentTree::<Sum<i32>, RangeAdd<i32>>::from(vec![]);
+        assert!(tree.is_empty());
+        assert_eq!(tree.query(..), 0);
+    }
+
+    #[test]
+    fn range_add_on_new_tree() {
+        let mut min = LazySegmentTree::<Min<u32>, RangeAdd<u32>>::new(4);
+        min.update(.., RangeAdd(1));
+        assert_eq!(min.query(..), u32::MAX);
+        min.set(2, 5);
+        min.update(1..3, RangeAdd(2));
+        assert_eq!(min.query(..), 7);
+
+        let mut max = LazySegmentTree::<Max<i32>, RangeAdd<i32>>::new(4);
+        max.update(.., RangeAdd(-1));
+        assert_eq!(max.query(..), i32::MIN);
+        max.set(0, -5);
+        max.update(..2, RangeAdd(-2));
+        assert_eq!(max.query(..), -7);
+    }
+
+    #[test]
+    fn lazy_new_is_identity() {
+        let mut tree = LazySegmentTree::<Min<u32>, RangeAssign<u32>>::new(6);
+        assert_eq!(tree.len(), 6);
+        assert_eq!(tree.query(..), u32::MAX);
+        tree.update(1..3, RangeAssign(4));
+        tree.set(5, 2);
+        assert_eq!(tree.query(..3), 4);
+        assert_eq!(tree.query(3..5), u32::MAX);
+        assert_eq!(tree.query(..), 2);
+    }
+
+    #[test]
+    fn point_update_range_query() {
+        let mut tree = SegmentTree::<Min<u32>>::new(5);
+        assert_eq!(tree.query(..), u32::MAX);
+        tree.set(3, 7);
+        tree.set(1, 9);
+        assert_eq!(tree.query(..), 7);
+        assert_eq!(tree.query(0..3), 9);
+        assert_eq!(tree.query(4..), u32::MAX);
+        assert_eq!(*tree.get(1), 9);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut seed = 7;
+        for len in 1..20 {
+            let mut values: Vec<i64> = (0..len).map(|_| lcg(&mut seed) as i64 % 100).collect();
+            let mut tree = SegmentTree::<Max<i64>>::from(values.clone());
+            for _ in 0..50 {
+                let index = lcg(&mut seed) as usize % len;
+                values[index] = lcg(&mut seed) as i64 % 100 - 50;
+                tree.set(index, values[index]);
+
+                let start = lcg(&mut seed) as usize % len;
+                let end = start + lcg(&mut seed) as usize % (len - start + 1);
+                let expected = values[start..end].iter().copied().max().unwrap_or(i64::MIN);
+                assert_eq!(tree.query(start..end), expected);
+            }
+        }
+    }
+
+    /// Not commutative, to make sure the order of elements is kept.
+    struct Concat;
+
+    impl Monoid for Concat {
+        type Value = Vec<u8>;
+
+        fn identity() -> Vec<u8> {
+            vec![]
+        }
+        fn combine(a: &Vec<u8>, b: &Vec<u8>) -> Vec<u8> {
+            a.iter().chain(b).copied().collect()
+        }
+    }
+
+    #[test]
+    fn keeps_order() {
+        let tree = SegmentTree::<Concat>::from((0..7).map(|i| vec![i]).collect::<Vec<_>>());
+        assert_eq!(tree.query(1..6), vec![1, 2, 3, 4, 5]);
+        assert_eq!(tree.query(..), vec![0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn range_add_sum() {
+        let mut tree = LazySegmentTree::<Sum<i32>, RangeAdd<i32>>::from(vec![0; 10]);
+        tree.update(2..6, RangeAdd(3));
+        tree.update(4..=8, RangeAdd(1));
+        assert_eq!(tree.query(..), 3 * 4 + 5);
+        assert_eq!(tree.query(5..7), 3 + 1 + 1);
+        assert_eq!(tree.get(4), 4);
+        assert_eq!(tree.get(9), 0);
+    }
+
+    #[test]
+    fn range_assign_then_add() {
+        let mut tree = LazySegmentTree::<Max<i32>, RangeAssign<i32>>::from(vec![1, 5, 2, 8]);
+        tree.update(1..3, RangeAssign(3));
+        assert_eq!(tree.query(..3), 3);
+        tree.set(2, 9);
+        assert_eq!(tree.query(..3), 9);
+        assert_eq!(tree.query(..), 9);
+    }
+
+    fn lazy_matches_brute_force<M, A>(apply: impl Fn(&mut i64, i64), fold: impl Fn(&[i64]) -> i64)
+    where
+        M: Monoid<Value = i64>,
+        A: Action<M> + From<i64>,
+    {
+        let mut seed = 3;
+        for len in 1..20 {
+            let mut values: Vec<i64> = (0..len).map(|_| lcg(&mut seed) as i64 % 100).collect();
+            let mut tree = LazySegmentTree::<M, A>::from(values.clone());
+            for _ in 0..50 {
+                let start = lcg(&mut seed) as usize % len;
+                let end = start + lcg(&mut seed) as usize % (len - start + 1);
+                let amount = lcg(&mut seed) as i64 % 20 - 10;
+                values[start..end].iter_mut().for_each(|v| apply(v, amount));
+                tree.update(start..end, A::from(amount));
+
+                let start = lcg(&mut seed) as usize % len;
+                let end = start + lcg(&mut seed) as usize % (len - start + 1);
+                assert_eq!(tree.query(start..end), fold(&values[start..end]));
+            }
+        }
+    }
+
+    impl From<i64> for RangeAdd<i64> {
+        fn from(value: i64) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<i64> for RangeAssign<i64> {
+        fn from(value: i64) -> Self {
+            Self(value)
+        }
+    }
+
+    #[test]
+    fn lazy_matches_brute_force_all() {
+        let add = |v: &mut i64, amount| *v += amount;
+        let assign = |v: &mut i64, amount| *v = amount;
+        let sum = |s: &[i64]| s.iter().sum();
+        let min = |s: &[i64]| s.iter().copied().min().unwrap_or(i64::MAX);
+        let max = |s: &[i64]| s.iter().copied().max().unwrap_or(i64::MIN);
+
+        lazy_matches_brute_force::<Sum<i64>, RangeAdd<i64>>(add, sum);
+        lazy_matches_brute_force::<Min<i64>, RangeAdd<i64>>(add, min);
+        lazy_matches_brute_force::<Max<i64>, RangeAdd<i64>>(add, max);
+        lazy_matches_brute_force::<Sum<i64>, RangeAssign<i64>>(assign, sum);
+        lazy_matches_brute_force::<Min<i64>, RangeAssign<i64>>(assign, min);
+        lazy_matches_brute_force::<Max<i64>, RangeAssign<i64>>(assign, max);
+    }
+}
@@ -0,0 +1,347 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use super::MinHeap;
+
+/// Keeps the `k` largest items pushed into it.
+///
+/// The smallest kept item sits at the top of a <code>[MinHeap]</code>, so it can be evicted when a
+/// larger one arrives.
+///
+/// Complexity:
+/// Runtime O(log k) for `push`, O(k * log k) for `into_sorted_vec`.
+/// Space O(k)
+///
+/// # Examples
+///
+/// The lengths of the 2 longest [Interval](crate::intervals::Interval)s, with their start:
+///
+/// ```
+/// use kupsy::ds::TopK;
+/// use kupsy::intervals::Interval;
+///
+/// let intervals = [Interval {start: 0, end: 4}, Interval {start: 5, end: 15}, Interval {start: 20, end: 27}];
+/// let mut longest = TopK::new(2);
+/// for interval in intervals {
+///     longest.push((interval.end - interval.start, interval.start));
+/// }
+/// assert_eq!(longest.into_sorted_vec(), vec![(10, 5), (7, 20)]);
+/// ```
+pub struct TopK<T>
+where
+    T: Ord,
+{
+    k: usize,
+    heap: MinHeap<T>,
+}
+
+impl<T> TopK<T>
+where
+    T: Ord,
+{
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: MinHeap::new(),
+        }
+    }
+    pub fn k(&self) -> usize {
+        self.k
+    }
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    /// The smallest kept item, which is the next one to be evicted.
+    pub fn peek_smallest(&self) -> Option<&T> {
+        self.heap.peek()
+    }
+    /// Keep `item` if it's among the `k` largest so far.
+    pub fn push(&mut self, item: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(item);
+        } else if self.heap.peek().is_some_and(|smallest| item > *smallest) {
+            self.heap.pop();
+            self.heap.push(item);
+        }
+    }
+    /// Get the kept items, largest first.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut items = Vec::with_capacity(self.heap.len());
+        while let Some(item) = self.heap.pop() {
+            items.push(item);
+        }
+        items.reverse();
+        items
+    }
+}
+
+impl<T> Extend<T> for TopK<T>
+where
+    T: Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+/// Keeps the `k` items with the largest keys pushed into it.
+///
+/// The key of every item is computed once, when it's pushed.
+///
+/// Complexity:
+/// Runtime O(log k) for `push`, O(k * log k) for `into_sorted_vec`.
+/// Space O(k)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::TopKByKey;
+///
+/// let mut busiest = TopKByKey::new(2, |&(_, requests): &(&str, u32)| requests);
+/// busiest.extend([("09:00", 12), ("10:00", 40), ("11:00", 7), ("12:00", 31)]);
+/// assert_eq!(busiest.into_sorted_vec(), vec![("10:00", 40), ("12:00", 31)]);
+/// ```
+pub struct TopKByKey<T, K, F>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    k: usize,
+    key: F,
+    heap: MinHeap<Keyed<K, T>>,
+}
+
+impl<T, K, F> TopKByKey<T, K, F>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    pub fn new(k: usize, key: F) -> Self {
+        Self {
+            k,
+            key,
+            heap: MinHeap::new(),
+        }
+    }
+    pub fn k(&self) -> usize {
+        self.k
+    }
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    /// The kept item with the smallest key, which is the next one to be evicted.
+    pub fn peek_smallest(&self) -> Option<&T> {
+        self.heap.peek().map(|keyed| &keyed.item)
+    }
+    /// Keep `item` if its key is among the `k` largest so far.
+    pub fn push(&mut self, item: T) {
+        if self.k == 0 {
+            return;
+        }
+        let keyed = Keyed {
+            key: (self.key)(&item),
+            item,
+        };
+        if self.heap.len() < self.k {
+            self.heap.push(keyed);
+        } else if self.heap.peek().is_some_and(|smallest| keyed > *smallest) {
+            self.heap.pop();
+            self.heap.push(keyed);
+        }
+    }
+    /// Get the kept items, largest key first.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut items = Vec::with_capacity(self.heap.len());
+        while let Some(keyed) = self.heap.pop() {
+            items.push(keyed.item);
+        }
+        items.reverse();
+        items
+    }
+}
+
+impl<T, K, F> Extend<T> for TopKByKey<T, K, F>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+/// Collect the `k` largest items of an [Iterator], largest first.
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::TopKIterator;
+/// use kupsy::intervals::Interval;
+///
+/// let intervals = [Interval {start: 0, end: 4}, Interval {start: 5, end: 15}, Interval {start: 20, end: 27}];
+///
+/// let longest = intervals.iter().top_k_by_key(1, |interval| interval.end - interval.start);
+/// assert_eq!(longest, vec![&Interval {start: 5, end: 15}]);
+///
+/// let earliest = intervals.iter().top_k_by(2, |a, b| b.start.cmp(&a.start));
+/// assert_eq!(earliest, vec![&Interval {start: 0, end: 4}, &Interval {start: 5, end: 15}]);
+/// ```
+pub trait TopKIterator: Iterator {
+    /// The `k` largest items.
+    fn top_k(self, k: usize) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        let mut top = TopK::new(k);
+        top.extend(self);
+        top.into_sorted_vec()
+    }
+    /// The `k` items with the largest keys.
+    fn top_k_by_key<K, F>(self, k: usize, key: F) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut top = TopKByKey::new(k, key);
+        top.extend(self);
+        top.into_sorted_vec()
+    }
+    /// The `k` largest items according to `compare`.
+    fn top_k_by<F>(self, k: usize, compare: F) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        F: Fn(&Self::Item, &Self::Item) -> Ordering,
+    {
+        let mut top = TopK::new(k);
+        top.extend(self.map(|item| ByCompare {
+            item,
+            compare: &compare,
+        }));
+        top.into_sorted_vec()
+            .into_iter()
+            .map(|wrapped| wrapped.item)
+            .collect()
+    }
+}
+
+impl<I> TopKIterator for I where I: Iterator {}
+
+/// Orders items by their key only.
+struct Keyed<K, T> {
+    key: K,
+    item: T,
+}
+
+impl<K: Ord, T> PartialEq for Keyed<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+impl<K: Ord, T> Eq for Keyed<K, T> {}
+impl<K: Ord, T> PartialOrd for Keyed<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<K: Ord, T> Ord for Keyed<K, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+/// Orders items with a comparator.
+struct ByCompare<'a, T, F> {
+    item: T,
+    compare: &'a F,
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering> PartialEq for ByCompare<'_, T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<T, F: Fn(&T, &T) -> Ordering> Eq for ByCompare<'_, T, F> {}
+impl<T, F: Fn(&T, &T) -> Ordering> PartialOrd for ByCompare<'_, T, F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<T, F: Fn(&T, &T) -> Ordering> Ord for ByCompare<'_, T, F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.compare)(&self.item, &other.item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::lcg;
+    use alloc::vec;
+
+    #[test]
+    fn zero_k() {
+        let mut top = TopK::new(0);
+        top.push(3);
+        assert!(top.is_empty());
+        assert_eq!([1, 2].into_iter().top_k_by_key(0, |&x| x), vec![]);
+    }
+
+    #[test]
+    fn fewer_items_than_k() {
+        let mut top = TopK::new(5);
+        top.extend([3, 1, 2]);
+        assert_eq!(top.len(), 3);
+        assert_eq!(top.peek_smallest(), Some(&1));
+        assert_eq!(top.into_sorted_vec(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn keeps_duplicates() {
+        assert_eq!([5, 1, 5, 3, 5].into_iter().top_k(2), vec![5, 5]);
+    }
+
+    #[test]
+    fn key_is_computed_once_per_item() {
+        let mut calls = 0;
+        let mut top = TopKByKey::new(2, |x: &i32| {
+            calls += 1;
+            -x
+        });
+        top.extend([4, 2, 8, 1]);
+        assert_eq!(top.into_sorted_vec(), vec![1, 2]);
+        assert_eq!(calls, 4);
+    }
+
+    #[test]
+    fn matches_sorting() {
+        let mut seed = 17;
+        let items: Vec<u64> = (0..500).map(|_| lcg(&mut seed) % 1000).collect();
+        let mut sorted = items.clone();
+        sorted.sort_unstable_by(|a, b| b.cmp(a));
+        for k in [1, 7, 100, 500, 600] {
+            let expected: Vec<u64> = sorted.iter().copied().take(k).collect();
+            assert_eq!(items.iter().copied().top_k(k), expected);
+            assert_eq!(items.iter().copied().top_k_by(k, |a, b| a.cmp(b)), expected);
+            let smallest: Vec<u64> = sorted.iter().rev().copied().take(k).collect();
+            assert_eq!(
+                items
+                    .iter()
+                    .copied()
+                    .top_k_by_key(k, |&x| core::cmp::Reverse(x)),
+                smallest
+            );
+        }
+    }
+}
@@ -0,0 +1,545 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::hash::Hash;
+use core::ops::Add;
+
+use hashbrown::HashMap;
+
+use crate::ds::MinHeap;
+
+/// Result of a search on an implicit graph.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SearchResult<N, W> {
+    /// The shortest path, from the start to the goal, both included, and its cost.
+    Found { path: Vec<N>, cost: W },
+    /// The search stopped at its limit. Has the path to the expanded node with the smallest
+    /// heuristic, which is the best guess of where the search was heading, and its cost.
+    LimitReached { path: Vec<N>, cost: W },
+    /// Every reachable node was expanded without finding a goal.
+    NotFound,
+}
+
+/// Find the shortest path from `start` to a node where `is_goal` holds, with A*.
+///
+/// The graph is given by `neighbors`, which returns the `(node, weight)` of every edge leaving a
+/// node, so grids and state spaces don't need to be stored. `heuristic` estimates the cost from
+/// a node to the closest goal, and the path is the shortest one as long as it never
+/// overestimates. A `heuristic` that always returns `W::default()` turns this into Dijkstra's
+/// algorithm.
+///
+/// # Complexity:
+///
+/// | Space    | Runtime            |
+/// |----------|--------------------|
+/// | O(n + m) | O((n + m) * log n) |
+///
+/// Where n = number of expanded nodes, m = number of their edges
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::graph::{SearchResult, astar};
+///
+/// // A 5x5 grid with a wall at x = 2, except at y = 4.
+/// let neighbors = |&(x, y): &(i32, i32)| {
+///     [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+///         .into_iter()
+///         .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y) && (x != 2 || y == 4))
+///         .map(|node| (node, 1))
+/// };
+/// let goal = (4, 0);
+/// let manhattan = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+///
+/// let SearchResult::Found { path, cost } = astar((0, 0), neighbors, |&node| node == goal, manhattan) else {
+///     panic!();
+/// };
+/// assert_eq!(cost, 12);
+/// assert_eq!(path.first(), Some(&(0, 0)));
+/// assert!(path.contains(&(2, 4)));
+/// ```
+///
+/// assumptions:
+/// No weight is negative, and `heuristic` never overestimates.
+pub fn astar<N, W, F, I, G, H>(
+    start: N,
+    neighbors: F,
+    is_goal: G,
+    heuristic: H,
+) -> SearchResult<N, W>
+where
+    N: Clone + Eq + Hash,
+    W: Copy + Ord + Default + Add<Output = W>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, W)>,
+    G: FnMut(&N) -> bool,
+    H: FnMut(&N) -> W,
+{
+    astar_with_limit(start, neighbors, is_goal, heuristic, usize::MAX)
+}
+
+/// <code>[astar]</code> that stops after expanding `max_expansions` nodes.
+///
+/// Returns <code>[SearchResult::LimitReached]</code> when it stops early, so large or infinite
+/// state spaces can be searched with a bounded amount of work.
+///
+/// # Complexity:
+///
+/// | Space    | Runtime            |
+/// |----------|--------------------|
+/// | O(n + m) | O((n + m) * log n) |
+///
+/// Where n = min(number of expanded nodes, max_expansions), m = number of their edges
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::graph::{SearchResult, astar_with_limit};
+///
+/// // An infinite line, where the goal can't be reached in 10 expansions.
+/// let result = astar_with_limit(
+///     0_i64,
+///     |&x: &i64| [(x - 1, 1), (x + 1, 1)],
+///     |&x| x == 100,
+///     |&x| (100 - x).abs(),
+///     10,
+/// );
+/// assert_eq!(
+///     result,
+///     SearchResult::LimitReached {path: (0..10).collect(), cost: 9}
+/// );
+/// ```
+///
+/// assumptions:
+/// No weight is negative, and `heuristic` never overestimates.
+pub fn astar_with_limit<N, W, F, I, G, H>(
+    start: N,
+    mut neighbors: F,
+    mut is_goal: G,
+    mut heuristic: H,
+    max_expansions: usize,
+) -> SearchResult<N, W>
+where
+    N: Clone + Eq + Hash,
+    W: Copy + Ord + Default + Add<Output = W>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, W)>,
+    G: FnMut(&N) -> bool,
+    H: FnMut(&N) -> W,
+{
+    let mut nodes = Interner::new();
+    let mut tree = SearchTree::new();
+    let mut heap = MinHeap::new();
+
+    let start_heuristic = heuristic(&start);
+    let start = nodes.id(start);
+    tree.set(start, W::default(), None);
+    // Ties on the estimated total are broken by the smaller heuristic, to go deeper first.
+    heap.push((start_heuristic, start_heuristic, W::default(), start));
+    let mut closest = (start_heuristic, W::default(), start);
+    let mut expansions = 0;
+
+    while let Some((_, node_heuristic, cost, node)) = heap.pop() {
+        if tree.cost(node).is_some_and(|best| cost > best) {
+            // Already reached through a cheaper path.
+            continue;
+        }
+        if is_goal(nodes.get(node)) {
+            return SearchResult::Found {
+                path: tree.path_to(node, &nodes),
+                cost,
+            };
+        }
+        if expansions == max_expansions {
+            let (_, cost, node) = closest;
+            return SearchResult::LimitReached {
+                path: tree.path_to(node, &nodes),
+                cost,
+            };
+        }
+        expansions += 1;
+        closest = closest.min((node_heuristic, cost, node));
+
+        for (next, weight) in neighbors(nodes.get(node)) {
+            let next_cost = cost + weight;
+            let next = nodes.id(next);
+            if !matches!(tree.cost(next), Some(best) if best <= next_cost) {
+                tree.set(next, next_cost, Some(node));
+                let next_heuristic = heuristic(nodes.get(next));
+                heap.push((next_cost + next_heuristic, next_heuristic, next_cost, next));
+            }
+        }
+    }
+
+    SearchResult::NotFound
+}
+
+/// Find the shortest path from `start` to `goal`, with Dijkstra's algorithm from both ends.
+///
+/// `neighbors` returns the `(node, weight)` of every edge leaving a node, and
+/// `reverse_neighbors` the ones of every edge entering it. For undirected graphs they're the
+/// same. Usually expands far fewer nodes than a search from one end.
+///
+/// # Complexity:
+///
+/// | Space    | Runtime            |
+/// |----------|--------------------|
+/// | O(n + m) | O((n + m) * log n) |
+///
+/// Where n = number of expanded nodes, m = number of their edges
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::graph::{Graph, SearchResult, bidirectional_dijkstra};
+///
+/// let mut graph = Graph::new_directed(4);
+/// graph.add_edge(0, 1, 4);
+/// graph.add_edge(0, 2, 1);
+/// graph.add_edge(2, 1, 2);
+/// graph.add_edge(1, 3, 5);
+/// let reversed = graph.reversed();
+///
+/// let result = bidirectional_dijkstra(
+///     0,
+///     3,
+///     |&node: &usize| graph.neighbors(node).iter().map(|edge| (edge.to, edge.weight)),
+///     |&node: &usize| reversed.neighbors(node).iter().map(|edge| (edge.to, edge.weight)),
+/// );
+/// assert_eq!(result, SearchResult::Found {path: vec![0, 2, 1, 3], cost: 8});
+/// ```
+///
+/// assumptions:
+/// No weight is negative.
+pub fn bidirectional_dijkstra<N, W, F, I, R, J>(
+    start: N,
+    goal: N,
+    mut neighbors: F,
+    mut reverse_neighbors: R,
+) -> SearchResult<N, W>
+where
+    N: Clone + Eq + Hash,
+    W: Copy + Ord + Default + Add<Output = W>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, W)>,
+    R: FnMut(&N) -> J,
+    J: IntoIterator<Item = (N, W)>,
+{
+    let mut nodes = Interner::new();
+    let start = nodes.id(start);
+    let goal = nodes.id(goal);
+    if start == goal {
+        return SearchResult::Found {
+            path: vec![nodes.get(start).clone()],
+            cost: W::default(),
+        };
+    }
+
+    let mut forward = Frontier::new(start);
+    let mut backward = Frontier::new(goal);
+    // Cost of the shortest path found so far, and a node where both searches met on it.
+    let mut best: Option<(W, usize)> = None;
+
+    // Once the two closest unexpanded nodes are together at least as far as the best path, no
+    // shorter path can be found. When one side runs out, every path was already seen.
+    while let (Some(&(forward_cost, _)), Some(&(backward_cost, _))) =
+        (forward.heap.peek(), backward.heap.peek())
+    {
+        if best.is_some_and(|(cost, _)| forward_cost + backward_cost >= cost) {
+            break;
+        }
+        if forward_cost <= backward_cost {
+            forward.expand(&mut nodes, &backward, &mut neighbors, &mut best);
+        } else {
+            backward.expand(&mut nodes, &forward, &mut reverse_neighbors, &mut best);
+        }
+    }
+
+    let Some((cost, meeting)) = best else {
+        return SearchResult::NotFound;
+    };
+    let mut path = forward.tree.path_to(meeting, &nodes);
+    let mut node = meeting;
+    while let Some(next) = backward.tree.previous(node) {
+        path.push(nodes.get(next).clone());
+        node = next;
+    }
+    SearchResult::Found { path, cost }
+}
+
+/// Gives every distinct node a dense id, so the heaps only need to order ids.
+struct Interner<N> {
+    nodes: Vec<N>,
+    ids: HashMap<N, usize>,
+}
+
+impl<N> Interner<N>
+where
+    N: Clone + Eq + Hash,
+{
+    fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+        }
+    }
+    fn id(&mut self, node: N) -> usize {
+        if let Some(&id) = self.ids.get(&node) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.ids.insert(node, id);
+        id
+    }
+    fn get(&self, id: usize) -> &N {
+        &self.nodes[id]
+    }
+}
+
+/// Best known cost of every reached node, and the node before it on that path.
+struct SearchTree<W> {
+    costs: Vec<Option<W>>,
+    previous: Vec<Option<usize>>,
+}
+
+impl<W> SearchTree<W>
+where
+    W: Copy,
+{
+    fn new() -> Self {
+        Self {
+            costs: Vec::new(),
+            previous: Vec::new(),
+        }
+    }
+    fn cost(&self, node: usize) -> Option<W> {
+        self.costs.get(node).copied().flatten()
+    }
+    fn previous(&self, node: usize) -> Option<usize> {
+        self.previous.get(node).copied().flatten()
+    }
+    fn set(&mut self, node: usize, cost: W, previous: Option<usize>) {
+        if node >= self.costs.len() {
+            self.costs.resize(node + 1, None);
+            self.previous.resize(node + 1, None);
+        }
+        self.costs[node] = Some(cost);
+        self.previous[node] = previous;
+    }
+    /// Nodes from the root to `node`, both included.
+    fn path_to<N: Clone + Eq + Hash>(&self, node: usize, nodes: &Interner<N>) -> Vec<N> {
+        let mut path = vec![nodes.get(node).clone()];
+        let mut current = node;
+        while let Some(previous) = self.previous(current) {
+            path.push(nodes.get(previous).clone());
+            current = previous;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// One side of a bidirectional search.
+struct Frontier<W>
+where
+    W: Ord,
+{
+    tree: SearchTree<W>,
+    heap: MinHeap<(W, usize)>,
+}
+
+impl<W> Frontier<W>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    fn new(root: usize) -> Self {
+        let mut tree = SearchTree::new();
+        tree.set(root, W::default(), None);
+        let mut heap = MinHeap::new();
+        heap.push((W::default(), root));
+        Self { tree, heap }
+    }
+
+    fn expand<N, F, I>(
+        &mut self,
+        nodes: &mut Interner<N>,
+        other: &Self,
+        neighbors: &mut F,
+        best: &mut Option<(W, usize)>,
+    ) where
+        N: Clone + Eq + Hash,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = (N, W)>,
+    {
+        let Some((cost, node)) = self.heap.pop() else {
+            return;
+        };
+        if self.tree.cost(node).is_some_and(|known| cost > known) {
+            return;
+        }
+        for (next, weight) in neighbors(nodes.get(node)) {
+            let next_cost = cost + weight;
+            let next = nodes.id(next);
+            if !matches!(self.tree.cost(next), Some(known) if known <= next_cost) {
+                self.tree.set(next, next_cost, Some(node));
+                self.heap.push((next_cost, next));
+            }
+            if let Some(other_cost) = other.tree.cost(next) {
+                let total = next_cost + other_cost;
+                if !matches!(best, Some((best_cost, _)) if *best_cost <= total) {
+                    *best = Some((total, next));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{dijkstra, Graph};
+    use crate::testing::lcg;
+
+    fn random_graph(seed: &mut u64, n: usize, m: usize) -> Graph<u64> {
+        let mut graph = Graph::new_directed(n);
+        for _ in 0..m {
+            let (from, to) = (lcg(seed) as usize % n, lcg(seed) as usize % n);
+            graph.add_edge(from, to, lcg(seed) % 20);
+        }
+        graph
+    }
+
+    fn path_cost(graph: &Graph<u64>, path: &[usize]) -> u64 {
+        path.windows(2)
+            .map(|pair| {
+                graph
+                    .neighbors(pair[0])
+                    .iter()
+                    .filter(|edge| edge.to == pair[1])
+                    .map(|edge| edge.weight)
+                    .min()
+                    .unwrap()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn start_is_goal() {
+        let no_edges = |_: &u32| [];
+        assert_eq!(
+            astar(3, no_edges, |&node| node == 3, |_| 0_u32),
+            SearchResult::Found {
+                path: vec![3],
+                cost: 0
+            }
+        );
+        assert_eq!(
+            bidirectional_dijkstra(3, 3, no_edges, no_edges),
+            SearchResult::Found {
+                path: vec![3],
+                cost: 0_u32
+            }
+        );
+    }
+
+    #[test]
+    fn unreachable() {
+        let line = |&x: &u32| (x < 5).then_some((x + 1, 1_u32));
+        let back = |&x: &u32| (1..=5).contains(&x).then_some((x - 1, 1_u32));
+        assert_eq!(astar(0, line, |&x| x == 9, |_| 0), SearchResult::NotFound);
+        assert_eq!(
+            astar_with_limit(0, line, |&x| x == 9, |_| 0, 6),
+            SearchResult::NotFound
+        );
+        assert_eq!(
+            bidirectional_dijkstra(0, 9, line, back),
+            SearchResult::NotFound
+        );
+    }
+
+    #[test]
+    fn zero_limit() {
+        assert_eq!(
+            astar_with_limit(0_i32, |&x: &i32| [(x + 1, 1)], |&x| x == 5, |&x| 5 - x, 0),
+            SearchResult::LimitReached {
+                path: vec![0],
+                cost: 0
+            }
+        );
+    }
+
+    #[test]
+    fn matches_dijkstra() {
+        let mut seed = 59;
+        for _ in 0..20 {
+            let n = 25;
+            let graph = random_graph(&mut seed, n, 70);
+            let reversed = graph.reversed();
+            let forward = |&node: &usize| {
+                graph
+                    .neighbors(node)
+                    .iter()
+                    .map(|edge| (edge.to, edge.weight))
+            };
+            let backward = |&node: &usize| {
+                reversed
+                    .neighbors(node)
+                    .iter()
+                    .map(|edge| (edge.to, edge.weight))
+            };
+            let (start, goal) = (lcg(&mut seed) as usize % n, lcg(&mut seed) as usize % n);
+            let expected = dijkstra(&graph, start).distance(goal);
+
+            for result in [
+                astar(start, forward, |&node| node == goal, |_| 0),
+                bidirectional_dijkstra(start, goal, forward, backward),
+            ] {
+                match result {
+                    SearchResult::Found { path, cost } => {
+                        assert_eq!(Some(cost), expected);
+                        assert_eq!(path.first(), Some(&start));
+                        assert_eq!(path.last(), Some(&goal));
+                        assert_eq!(path_cost(&graph, &path), cost);
+                    }
+                    SearchResult::NotFound => assert_eq!(expected, None),
+                    SearchResult::LimitReached { .. } => unreachable!(),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn heuristic_expands_fewer_nodes() {
+        let goal = (30, 30);
+        let mut expanded_with = 0;
+        let mut expanded_without = 0;
+        let grid = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..=40).contains(&x) && (0..=40).contains(&y))
+                .map(|node| (node, 1))
+        };
+        let manhattan = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+        let with = astar(
+            (0, 0),
+            |node: &(i32, i32)| {
+                expanded_with += 1;
+                grid(node)
+            },
+            |&node| node == goal,
+            manhattan,
+        );
+        let without = astar(
+            (0, 0),
+            |node: &(i32, i32)| {
+                expanded_without += 1;
+                grid(node)
+            },
+            |&node| node == goal,
+            |_| 0,
+        );
+        assert!(matches!(with, SearchResult::Found { cost: 60, .. }));
+        assert!(matches!(without, SearchResult::Found { cost: 60, .. }));
+        assert!(expanded_with < expanded_without);
+    }
+}
@@ -0,0 +1,184 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+mod implicit;
+mod mst;
+mod scc;
+mod shortest_path;
+mod topological;
+mod traversal;
+
+pub use implicit::*;
+pub use mst::*;
+pub use scc::*;
+pub use shortest_path::*;
+pub use topological::*;
+pub use traversal::*;
+
+/// Edge to `to`, stored in the adjacency list of its source node.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Edge<W>
+where
+    W: Copy,
+{
+    pub to: usize,
+    pub weight: W,
+}
+
+/// Graph over the nodes `0..node_count`, stored as adjacency lists.
+///
+/// Use `()` as `W` for unweighted graphs.
+///
+/// Complexity:
+/// Runtime O(1) for `add_node` and `add_edge`.
+/// Space O(n + m)
+/// Where n = number of nodes, m = number of edges.
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::graph::Graph;
+///
+/// let mut graph = Graph::new_undirected(3);
+/// graph.add_edge(0, 1, 5);
+/// graph.add_edge(1, 2, 1);
+/// assert_eq!(graph.edge_count(), 2);
+/// assert_eq!(graph.neighbors(1).len(), 2);
+/// assert_eq!(graph.edges().collect::<Vec<_>>(), vec![(0, 1, 5), (1, 2, 1)]);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Graph<W>
+where
+    W: Copy,
+{
+    adjacency: Vec<Vec<Edge<W>>>,
+    directed: bool,
+    edge_count: usize,
+}
+
+impl<W> Graph<W>
+where
+    W: Copy,
+{
+    /// Directed graph with `node_count` nodes and no edges.
+    pub fn new_directed(node_count: usize) -> Self {
+        Self::new(node_count, true)
+    }
+    /// Undirected graph with `node_count` nodes and no edges.
+    pub fn new_undirected(node_count: usize) -> Self {
+        Self::new(node_count, false)
+    }
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+    /// Number of edges added, where an undirected edge counts once.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+    pub fn nodes(&self) -> Range<usize> {
+        0..self.adjacency.len()
+    }
+    /// Add a node without edges, and return it.
+    pub fn add_node(&mut self) -> usize {
+        self.adjacency.push(Vec::new());
+        self.adjacency.len() - 1
+    }
+    /// Add an edge from `from` to `to`, which also goes from `to` to `from` in an undirected
+    /// graph.
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        assert!(
+            from < self.node_count() && to < self.node_count(),
+            "node out of bounds"
+        );
+        self.adjacency[from].push(Edge { to, weight });
+        if !self.directed && from != to {
+            self.adjacency[to].push(Edge { to: from, weight });
+        }
+        self.edge_count += 1;
+    }
+    /// Edges leaving `node`, in the order they were added.
+    pub fn neighbors(&self, node: usize) -> &[Edge<W>] {
+        &self.adjacency[node]
+    }
+    /// Every edge as `(from, to, weight)`, with undirected edges only once, from their smaller
+    /// node.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, W)> + '_ {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, edges)| {
+                edges
+                    .iter()
+                    .filter(move |edge| self.directed || from <= edge.to)
+                    .map(move |edge| (from, edge.to, edge.weight))
+            })
+    }
+    /// The same graph with every edge reversed. Undirected graphs are returned as they are.
+    pub fn reversed(&self) -> Self {
+        if !self.directed {
+            return self.clone();
+        }
+        let mut reversed = Self::new_directed(self.node_count());
+        for (from, to, weight) in self.edges() {
+            reversed.add_edge(to, from, weight);
+        }
+        reversed
+    }
+
+    fn new(node_count: usize, directed: bool) -> Self {
+        Self {
+            adjacency: (0..node_count).map(|_| Vec::new()).collect(),
+            directed,
+            edge_count: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn directed_edges() {
+        let mut graph = Graph::new_directed(3);
+        graph.add_edge(2, 0, ());
+        graph.add_edge(0, 1, ());
+        assert_eq!(graph.neighbors(0), &[Edge { to: 1, weight: () }]);
+        assert!(graph.neighbors(1).is_empty());
+        assert_eq!(
+            graph.reversed().edges().collect::<Vec<_>>(),
+            vec![(0, 2, ()), (1, 0, ())]
+        );
+    }
+
+    #[test]
+    fn undirected_self_loop_is_stored_once() {
+        let mut graph = Graph::new_undirected(2);
+        graph.add_edge(1, 1, 4);
+        graph.add_edge(1, 0, 2);
+        assert_eq!(graph.neighbors(1).len(), 2);
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(
+            graph.edges().collect::<Vec<_>>(),
+            vec![(0, 1, 2), (1, 1, 4)]
+        );
+    }
+
+    #[test]
+    fn add_node() {
+        let mut graph = Graph::<()>::new_directed(0);
+        assert_eq!(graph.add_node(), 0);
+        assert_eq!(graph.add_node(), 1);
+        assert_eq!(graph.nodes(), 0..2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn edge_out_of_bounds() {
+        Graph::new_directed(2).add_edge(0, 2, ());
+    }
+}
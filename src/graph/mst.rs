@@ -0,0 +1,149 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::Graph;
+use crate::ds::{DisjointSet, MinHeap};
+
+/// Minimum spanning forest with Kruskal's algorithm, using a <code>[DisjointSet]</code>.
+///
+/// Returns the chosen edges as `(from, to, weight)`, by increasing weight. Panics if `graph` is
+/// directed.
+///
+/// # Complexity:
+///
+/// | Space | Runtime      |
+/// |-------|--------------|
+/// | O(m)  | O(m * log m) |
+///
+/// Where m = number of edges
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::graph::{Graph, kruskal};
+///
+/// let mut graph = Graph::new_undirected(4);
+/// graph.add_edge(0, 1, 3);
+/// graph.add_edge(1, 2, 1);
+/// graph.add_edge(0, 2, 2);
+/// graph.add_edge(2, 3, 7);
+/// assert_eq!(kruskal(&graph), vec![(1, 2, 1), (0, 2, 2), (2, 3, 7)]);
+/// ```
+pub fn kruskal<W>(graph: &Graph<W>) -> Vec<(usize, usize, W)>
+where
+    W: Copy + Ord,
+{
+    assert!(!graph.is_directed(), "graph must be undirected");
+    let mut edges: Vec<(usize, usize, W)> = graph.edges().collect();
+    edges.sort_by_key(|&(_, _, weight)| weight);
+    let mut sets = DisjointSet::new(graph.node_count());
+    edges
+        .into_iter()
+        .filter(|&(from, to, _)| sets.union(from, to))
+        .collect()
+}
+
+/// Minimum spanning forest with Prim's algorithm, using a <code>[MinHeap]</code>.
+///
+/// Returns the chosen edges as `(from, to, weight)`, in the order they were added, where `from`
+/// was already in the tree. Every tree is grown from its smallest node. Panics if `graph` is
+/// directed.
+///
+/// # Complexity:
+///
+/// | Space    | Runtime            |
+/// |----------|--------------------|
+/// | O(n + m) | O((n + m) * log m) |
+///
+/// Where n = number of nodes, m = number of edges
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::graph::{Graph, prim};
+///
+/// let mut graph = Graph::new_undirected(4);
+/// graph.add_edge(0, 1, 3);
+/// graph.add_edge(1, 2, 1);
+/// graph.add_edge(0, 2, 2);
+/// graph.add_edge(2, 3, 7);
+/// assert_eq!(prim(&graph), vec![(0, 2, 2), (2, 1, 1), (2, 3, 7)]);
+/// ```
+pub fn prim<W>(graph: &Graph<W>) -> Vec<(usize, usize, W)>
+where
+    W: Copy + Ord,
+{
+    assert!(!graph.is_directed(), "graph must be undirected");
+    let mut in_tree = vec![false; graph.node_count()];
+    let mut chosen = Vec::new();
+    let mut heap = MinHeap::new();
+    for root in graph.nodes() {
+        if in_tree[root] {
+            continue;
+        }
+        in_tree[root] = true;
+        for edge in graph.neighbors(root) {
+            heap.push((edge.weight, root, edge.to));
+        }
+        while let Some((weight, from, to)) = heap.pop() {
+            if in_tree[to] {
+                continue;
+            }
+            in_tree[to] = true;
+            chosen.push((from, to, weight));
+            for edge in graph.neighbors(to) {
+                if !in_tree[edge.to] {
+                    heap.push((edge.weight, to, edge.to));
+                }
+            }
+        }
+    }
+    chosen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::lcg;
+
+    fn total<W: Copy + core::iter::Sum<W>>(edges: &[(usize, usize, W)]) -> W {
+        edges.iter().map(|&(_, _, weight)| weight).sum()
+    }
+
+    #[test]
+    fn forest() {
+        let mut graph = Graph::new_undirected(5);
+        graph.add_edge(0, 1, 4);
+        graph.add_edge(3, 4, 2);
+        graph.add_edge(3, 4, 1);
+        graph.add_edge(2, 2, 0);
+        assert_eq!(kruskal(&graph), vec![(3, 4, 1), (0, 1, 4)]);
+        assert_eq!(prim(&graph), vec![(0, 1, 4), (3, 4, 1)]);
+    }
+
+    #[test]
+    fn kruskal_and_prim_agree() {
+        let mut seed = 53;
+        let n = 40;
+        let mut graph = Graph::new_undirected(n);
+        for _ in 0..150 {
+            let (from, to) = (lcg(&mut seed) as usize % n, lcg(&mut seed) as usize % n);
+            graph.add_edge(from, to, lcg(&mut seed) % 100);
+        }
+        let by_kruskal = kruskal(&graph);
+        let by_prim = prim(&graph);
+        assert_eq!(by_kruskal.len(), by_prim.len());
+        assert_eq!(total(&by_kruskal), total(&by_prim));
+
+        let mut sets = DisjointSet::new(n);
+        for &(from, to, _) in &by_prim {
+            assert!(sets.union(from, to));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn directed() {
+        kruskal(&Graph::<u32>::new_directed(1));
+    }
+}
@@ -0,0 +1,167 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::min;
+
+use super::Graph;
+
+/// Split the nodes into strongly connected components, with Tarjan's algorithm.
+///
+/// Components are returned in reverse topological order, so every edge between two components
+/// goes to an earlier one. The nodes of every component are sorted. Doesn't recurse, so deep
+/// graphs can't overflow the stack.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(n)  | O(n + m) |
+///
+/// Where n = number of nodes, m = number of edges
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::graph::{Graph, strongly_connected_components};
+///
+/// let mut graph = Graph::new_directed(5);
+/// for (from, to) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3)] {
+///     graph.add_edge(from, to, ());
+/// }
+/// assert_eq!(
+///     strongly_connected_components(&graph),
+///     vec![vec![3, 4], vec![0, 1, 2]]
+/// );
+/// ```
+pub fn strongly_connected_components<W>(graph: &Graph<W>) -> Vec<Vec<usize>>
+where
+    W: Copy,
+{
+    const UNVISITED: usize = usize::MAX;
+    let n = graph.node_count();
+    let mut index = vec![UNVISITED; n];
+    let mut low_link = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut next_index = 0;
+    let mut components = Vec::new();
+
+    for root in graph.nodes() {
+        if index[root] != UNVISITED {
+            continue;
+        }
+        // Simulated recursion, with the index of the next edge to follow.
+        let mut calls = vec![(root, 0)];
+        index[root] = next_index;
+        low_link[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some((node, next_edge)) = calls.last_mut() {
+            let node = *node;
+            if let Some(edge) = graph.neighbors(node).get(*next_edge) {
+                *next_edge += 1;
+                let to = edge.to;
+                if index[to] == UNVISITED {
+                    index[to] = next_index;
+                    low_link[to] = next_index;
+                    next_index += 1;
+                    stack.push(to);
+                    on_stack[to] = true;
+                    calls.push((to, 0));
+                } else if on_stack[to] {
+                    low_link[node] = min(low_link[node], index[to]);
+                }
+                continue;
+            }
+
+            calls.pop();
+            if let Some(&(parent, _)) = calls.last() {
+                low_link[parent] = min(low_link[parent], low_link[node]);
+            }
+            if low_link[node] == index[node] {
+                let mut component = Vec::new();
+                loop {
+                    let member = stack.pop().unwrap();
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_and_isolated() {
+        assert!(strongly_connected_components(&Graph::<()>::new_directed(0)).is_empty());
+        assert_eq!(
+            strongly_connected_components(&Graph::<()>::new_directed(2)),
+            vec![vec![0], vec![1]]
+        );
+    }
+
+    #[test]
+    fn dag_is_reverse_topological() {
+        let mut graph = Graph::new_directed(3);
+        graph.add_edge(0, 1, ());
+        graph.add_edge(1, 2, ());
+        assert_eq!(
+            strongly_connected_components(&graph),
+            vec![vec![2], vec![1], vec![0]]
+        );
+    }
+
+    #[test]
+    fn edges_go_to_earlier_components() {
+        let mut graph = Graph::new_directed(8);
+        for (from, to) in [
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (6, 5),
+            (6, 7),
+            (7, 6),
+        ] {
+            graph.add_edge(from, to, ());
+        }
+        let components = strongly_connected_components(&graph);
+        assert_eq!(components.len(), 3);
+        let mut component_of = [0; 8];
+        for (i, component) in components.iter().enumerate() {
+            for &node in component {
+                component_of[node] = i;
+            }
+        }
+        for (from, to, _) in graph.edges() {
+            assert!(component_of[to] <= component_of[from]);
+        }
+        assert!(components.contains(&vec![6, 7]));
+    }
+
+    #[test]
+    fn long_cycle() {
+        let n = 100_000;
+        let mut graph = Graph::new_directed(n);
+        for node in 0..n {
+            graph.add_edge(node, (node + 1) % n, ());
+        }
+        let components = strongly_connected_components(&graph);
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].len(), n);
+    }
+}
@@ -0,0 +1,180 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Add;
+
+use super::Graph;
+use crate::ds::MinHeap;
+
+/// Shortest paths from one source, returned by <code>[dijkstra]</code>.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ShortestPaths<W>
+where
+    W: Copy,
+{
+    source: usize,
+    distances: Vec<Option<W>>,
+    previous: Vec<Option<usize>>,
+}
+
+impl<W> ShortestPaths<W>
+where
+    W: Copy,
+{
+    pub fn source(&self) -> usize {
+        self.source
+    }
+    /// Length of the shortest path to `node`, or `None` if it can't be reached.
+    pub fn distance(&self, node: usize) -> Option<W> {
+        self.distances[node]
+    }
+    /// The node before `node` on its shortest path.
+    pub fn previous(&self, node: usize) -> Option<usize> {
+        self.previous[node]
+    }
+    /// Nodes on the shortest path from the source to `node`, both included.
+    pub fn path_to(&self, node: usize) -> Option<Vec<usize>> {
+        self.distances[node]?;
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(previous) = self.previous[current] {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Get the shortest paths from `source` to every node, using a <code>[MinHeap]</code>.
+///
+/// # Complexity:
+///
+/// | Space     | Runtime            |
+/// |-----------|--------------------|
+/// | O(n + m)  | O((n + m) * log n) |
+///
+/// Where n = number of nodes, m = number of edges
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::graph::{Graph, dijkstra};
+///
+/// let mut graph = Graph::new_directed(4);
+/// graph.add_edge(0, 1, 4);
+/// graph.add_edge(0, 2, 1);
+/// graph.add_edge(2, 1, 2);
+/// graph.add_edge(1, 3, 5);
+///
+/// let paths = dijkstra(&graph, 0);
+/// assert_eq!(paths.distance(3), Some(8));
+/// assert_eq!(paths.path_to(3), Some(vec![0, 2, 1, 3]));
+/// ```
+///
+/// assumptions:
+/// No weight is negative.
+pub fn dijkstra<W>(graph: &Graph<W>, source: usize) -> ShortestPaths<W>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    let mut distances: Vec<Option<W>> = vec![None; graph.node_count()];
+    let mut previous = vec![None; graph.node_count()];
+    let mut heap = MinHeap::new();
+    distances[source] = Some(W::default());
+    heap.push((W::default(), source));
+
+    while let Some((distance, node)) = heap.pop() {
+        if distances[node].is_some_and(|best| distance > best) {
+            // Already reached through a shorter path.
+            continue;
+        }
+        for edge in graph.neighbors(node) {
+            let candidate = distance + edge.weight;
+            if !matches!(distances[edge.to], Some(best) if best <= candidate) {
+                distances[edge.to] = Some(candidate);
+                previous[edge.to] = Some(node);
+                heap.push((candidate, edge.to));
+            }
+        }
+    }
+
+    ShortestPaths {
+        source,
+        distances,
+        previous,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::lcg;
+
+    #[test]
+    fn unreachable() {
+        let mut graph = Graph::new_directed(3);
+        graph.add_edge(1, 0, 1_u32);
+        let paths = dijkstra(&graph, 0);
+        assert_eq!(paths.distance(0), Some(0));
+        assert_eq!(paths.path_to(0), Some(vec![0]));
+        assert_eq!(paths.distance(1), None);
+        assert_eq!(paths.path_to(2), None);
+    }
+
+    #[test]
+    fn undirected_with_zero_weights() {
+        let mut graph = Graph::new_undirected(5);
+        graph.add_edge(0, 1, 0_u64);
+        graph.add_edge(1, 2, 3);
+        graph.add_edge(0, 3, 1);
+        graph.add_edge(3, 2, 1);
+        graph.add_edge(2, 4, 0);
+        let paths = dijkstra(&graph, 4);
+        assert_eq!(paths.source(), 4);
+        assert_eq!(paths.distance(1), Some(2));
+        assert_eq!(paths.path_to(1), Some(vec![4, 2, 3, 0, 1]));
+        assert_eq!(paths.previous(2), Some(4));
+    }
+
+    #[test]
+    fn matches_bellman_ford() {
+        let mut seed = 47;
+        let n = 30;
+        let mut graph = Graph::new_directed(n);
+        for _ in 0..120 {
+            let (from, to) = (lcg(&mut seed) as usize % n, lcg(&mut seed) as usize % n);
+            graph.add_edge(from, to, lcg(&mut seed) % 20);
+        }
+        let paths = dijkstra(&graph, 0);
+
+        let mut expected: Vec<Option<u64>> = vec![None; n];
+        expected[0] = Some(0);
+        for _ in 0..n {
+            for (from, to, weight) in graph.edges() {
+                if let Some(distance) = expected[from] {
+                    if !matches!(expected[to], Some(best) if best <= distance + weight) {
+                        expected[to] = Some(distance + weight);
+                    }
+                }
+            }
+        }
+        for (node, &distance) in expected.iter().enumerate() {
+            assert_eq!(paths.distance(node), distance);
+            if let Some(path) = paths.path_to(node) {
+                let length: u64 = path
+                    .windows(2)
+                    .map(|pair| {
+                        graph
+                            .neighbors(pair[0])
+                            .iter()
+                            .filter(|edge| edge.to == pair[1])
+                            .map(|edge| edge.weight)
+                            .min()
+                            .unwrap()
+                    })
+                    .sum();
+                assert_eq!(Some(length), distance);
+            }
+        }
+    }
+}
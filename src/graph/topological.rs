@@ -0,0 +1,151 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::Graph;
+
+/// Cycle that prevents a topological order, returned by <code>[topological_sort]</code>.
+///
+/// Every node has an edge to the next one, and the last one has an edge to the first one.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Cycle {
+    pub nodes: Vec<usize>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Unvisited,
+    // On the current DFS path.
+    Active,
+    Done,
+}
+
+/// Order the nodes so that every edge goes from an earlier node to a later one.
+///
+/// Returns a <code>[Cycle]</code> if there's no such order. Panics if `graph` is undirected.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(n)  | O(n + m) |
+///
+/// Where n = number of nodes, m = number of edges
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::graph::{Cycle, Graph, topological_sort};
+///
+/// let mut graph = Graph::new_directed(4);
+/// graph.add_edge(2, 0, ());
+/// graph.add_edge(0, 1, ());
+/// graph.add_edge(3, 1, ());
+/// assert_eq!(topological_sort(&graph), Ok(vec![3, 2, 0, 1]));
+///
+/// graph.add_edge(1, 2, ());
+/// assert_eq!(topological_sort(&graph), Err(Cycle {nodes: vec![0, 1, 2]}));
+/// ```
+pub fn topological_sort<W>(graph: &Graph<W>) -> Result<Vec<usize>, Cycle>
+where
+    W: Copy,
+{
+    assert!(graph.is_directed(), "graph must be directed");
+    let mut state = vec![State::Unvisited; graph.node_count()];
+    let mut postorder = Vec::with_capacity(graph.node_count());
+    for root in graph.nodes() {
+        if state[root] != State::Unvisited {
+            continue;
+        }
+        // The current DFS path, with the index of the next edge to follow.
+        let mut stack = vec![(root, 0)];
+        state[root] = State::Active;
+        while let Some((node, next_edge)) = stack.last_mut() {
+            let node = *node;
+            let Some(edge) = graph.neighbors(node).get(*next_edge) else {
+                state[node] = State::Done;
+                postorder.push(node);
+                stack.pop();
+                continue;
+            };
+            *next_edge += 1;
+            match state[edge.to] {
+                State::Unvisited => {
+                    state[edge.to] = State::Active;
+                    stack.push((edge.to, 0));
+                }
+                State::Active => {
+                    let start = stack.iter().position(|&(n, _)| n == edge.to).unwrap();
+                    return Err(Cycle {
+                        nodes: stack[start..].iter().map(|&(n, _)| n).collect(),
+                    });
+                }
+                State::Done => {}
+            }
+        }
+    }
+    postorder.reverse();
+    Ok(postorder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_topological(graph: &Graph<()>, order: &[usize]) {
+        let mut position = vec![usize::MAX; graph.node_count()];
+        for (i, &node) in order.iter().enumerate() {
+            position[node] = i;
+        }
+        assert_eq!(order.len(), graph.node_count());
+        for (from, to, _) in graph.edges() {
+            assert!(position[from] < position[to]);
+        }
+    }
+
+    fn assert_cycle(graph: &Graph<()>, cycle: &Cycle) {
+        assert!(!cycle.nodes.is_empty());
+        for (i, &from) in cycle.nodes.iter().enumerate() {
+            let to = cycle.nodes[(i + 1) % cycle.nodes.len()];
+            assert!(graph.neighbors(from).iter().any(|edge| edge.to == to));
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(topological_sort(&Graph::<()>::new_directed(0)), Ok(vec![]));
+    }
+
+    #[test]
+    fn self_loop() {
+        let mut graph = Graph::new_directed(2);
+        graph.add_edge(1, 1, ());
+        assert_eq!(topological_sort(&graph), Err(Cycle { nodes: vec![1] }));
+    }
+
+    #[test]
+    fn diamond() {
+        let mut graph = Graph::new_directed(5);
+        for (from, to) in [(0, 1), (0, 2), (1, 3), (2, 3), (4, 0), (4, 3)] {
+            graph.add_edge(from, to, ());
+        }
+        let order = topological_sort(&graph).unwrap();
+        assert_topological(&graph, &order);
+    }
+
+    #[test]
+    fn cycle_behind_dag_part() {
+        let mut graph = Graph::new_directed(6);
+        for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 4), (4, 2), (5, 0)] {
+            graph.add_edge(from, to, ());
+        }
+        let cycle = topological_sort(&graph).unwrap_err();
+        assert_eq!(cycle.nodes, vec![2, 3, 4]);
+        assert_cycle(&graph, &cycle);
+    }
+
+    #[test]
+    #[should_panic]
+    fn undirected() {
+        let _ = topological_sort(&Graph::<()>::new_undirected(1));
+    }
+}
@@ -0,0 +1,131 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::Graph;
+
+/// Nodes reachable from `source`, in breadth first order.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(n)  | O(n + m) |
+///
+/// Where n = number of nodes, m = number of edges
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::graph::{Graph, bfs};
+///
+/// let mut graph = Graph::new_directed(5);
+/// graph.add_edge(0, 1, ());
+/// graph.add_edge(1, 3, ());
+/// graph.add_edge(0, 2, ());
+/// graph.add_edge(4, 0, ());
+/// assert_eq!(bfs(&graph, 0), vec![0, 1, 2, 3]);
+/// ```
+pub fn bfs<W>(graph: &Graph<W>, source: usize) -> Vec<usize>
+where
+    W: Copy,
+{
+    let mut visited = vec![false; graph.node_count()];
+    let mut order = Vec::new();
+    let mut queue = VecDeque::from([source]);
+    visited[source] = true;
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+        for edge in graph.neighbors(node) {
+            if !visited[edge.to] {
+                visited[edge.to] = true;
+                queue.push_back(edge.to);
+            }
+        }
+    }
+    order
+}
+
+/// Nodes reachable from `source`, in depth first preorder.
+///
+/// Neighbors are visited in the order their edges were added. Doesn't recurse, so deep graphs
+/// can't overflow the stack.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(n)  | O(n + m) |
+///
+/// Where n = number of nodes, m = number of edges
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::graph::{Graph, dfs};
+///
+/// let mut graph = Graph::new_directed(5);
+/// graph.add_edge(0, 1, ());
+/// graph.add_edge(1, 3, ());
+/// graph.add_edge(0, 2, ());
+/// graph.add_edge(4, 0, ());
+/// assert_eq!(dfs(&graph, 0), vec![0, 1, 3, 2]);
+/// ```
+pub fn dfs<W>(graph: &Graph<W>, source: usize) -> Vec<usize>
+where
+    W: Copy,
+{
+    let mut visited = vec![false; graph.node_count()];
+    let mut order = vec![source];
+    // Nodes on the current path, with the index of their next edge to follow.
+    let mut stack = vec![(source, 0)];
+    visited[source] = true;
+    while let Some((node, next_edge)) = stack.last_mut() {
+        let Some(edge) = graph.neighbors(*node).get(*next_edge) else {
+            stack.pop();
+            continue;
+        };
+        *next_edge += 1;
+        if !visited[edge.to] {
+            visited[edge.to] = true;
+            order.push(edge.to);
+            stack.push((edge.to, 0));
+        }
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_node() {
+        let graph = Graph::<()>::new_directed(1);
+        assert_eq!(bfs(&graph, 0), vec![0]);
+        assert_eq!(dfs(&graph, 0), vec![0]);
+    }
+
+    #[test]
+    fn undirected_with_cycle() {
+        let mut graph = Graph::new_undirected(6);
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (2, 3), (0, 4)] {
+            graph.add_edge(from, to, ());
+        }
+        assert_eq!(bfs(&graph, 0), vec![0, 1, 2, 4, 3]);
+        assert_eq!(dfs(&graph, 0), vec![0, 1, 2, 3, 4]);
+        assert_eq!(bfs(&graph, 5), vec![5]);
+    }
+
+    #[test]
+    fn deep_path() {
+        let n = 100_000;
+        let mut graph = Graph::new_directed(n);
+        for node in 1..n {
+            graph.add_edge(node - 1, node, ());
+        }
+        let expected: Vec<usize> = (0..n).collect();
+        assert_eq!(dfs(&graph, 0), expected);
+        assert_eq!(bfs(&graph, 0), expected);
+    }
+}
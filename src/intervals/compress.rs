@@ -0,0 +1,190 @@
+use alloc::vec::Vec;
+
+use super::Interval;
+use crate::search::binary_search;
+
+/// Maps the endpoints of [Interval]s to their rank, and back.
+///
+/// Ranks are dense, so compressed [Interval]s can be used as indices into array based
+/// structures, like <code>[FenwickTree]</code> or <code>[SegmentTree]</code>.
+///
+/// [FenwickTree]: crate::ds::FenwickTree
+/// [SegmentTree]: crate::ds::SegmentTree
+///
+/// Complexity:
+/// Runtime O(n * log n) for building, O(log n) for `rank`, O(1) for `value`.
+/// Space O(n)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{CoordinateCompressor, Interval};
+///
+/// let intervals = [Interval {start: 100, end: 250}, Interval {start: 120, end: 180}];
+/// let compressor = CoordinateCompressor::new(&intervals);
+///
+/// assert_eq!(compressor.len(), 4);
+/// assert_eq!(compressor.rank(&180), Some(2));
+/// assert_eq!(compressor.value(3), Some(250));
+/// assert_eq!(
+///     compressor.compress(&intervals[1]),
+///     Some(Interval {start: 1, end: 2})
+/// );
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CoordinateCompressor<T>
+where
+    T: Copy + Ord,
+{
+    values: Vec<T>,
+}
+
+impl<T> CoordinateCompressor<T>
+where
+    T: Copy + Ord,
+{
+    /// Collect the `start` and `end` of every [Interval].
+    pub fn new(intervals: &[Interval<T>]) -> Self {
+        intervals
+            .iter()
+            .flat_map(|interval| [interval.start, interval.end])
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// All the values, sorted and without duplicates. A value's index is its rank.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Get the rank of `value`, if it was collected.
+    pub fn rank(&self, value: &T) -> Option<usize> {
+        binary_search(&self.values, value)
+    }
+
+    /// Get the value with rank `rank`.
+    pub fn value(&self, rank: usize) -> Option<T> {
+        self.values.get(rank).copied()
+    }
+
+    /// Number of collected values smaller than `value`.
+    ///
+    /// Same as `rank` for collected values, and also works for values in between.
+    pub fn lower_bound(&self, value: &T) -> usize {
+        self.values.partition_point(|v| v < value)
+    }
+
+    /// Number of collected values smaller than, or equal to `value`.
+    pub fn upper_bound(&self, value: &T) -> usize {
+        self.values.partition_point(|v| v <= value)
+    }
+
+    /// Turn an [Interval] into rank space.
+    ///
+    /// Returns `None` if one of its endpoints wasn't collected.
+    pub fn compress(&self, interval: &Interval<T>) -> Option<Interval<usize>> {
+        Some(Interval {
+            start: self.rank(&interval.start)?,
+            end: self.rank(&interval.end)?,
+        })
+    }
+
+    /// Turn every [Interval] into rank space.
+    ///
+    /// Returns `None` if an endpoint wasn't collected.
+    pub fn compress_all(&self, intervals: &[Interval<T>]) -> Option<Vec<Interval<usize>>> {
+        intervals
+            .iter()
+            .map(|interval| self.compress(interval))
+            .collect()
+    }
+
+    /// Turn an [Interval] in rank space back into values.
+    ///
+    /// Panics if a rank is out of bounds.
+    pub fn decompress(&self, interval: &Interval<usize>) -> Interval<T> {
+        Interval {
+            start: self.values[interval.start],
+            end: self.values[interval.end],
+        }
+    }
+}
+
+impl<T> FromIterator<T> for CoordinateCompressor<T>
+where
+    T: Copy + Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut values: Vec<T> = iter.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+        Self { values }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intervals::testing::tiv;
+
+    #[test]
+    fn empty() {
+        let compressor = CoordinateCompressor::new(&tiv![]);
+        assert!(compressor.is_empty());
+        assert_eq!(compressor.rank(&3), None);
+        assert_eq!(compressor.lower_bound(&3), 0);
+    }
+
+    #[test]
+    fn dedups_and_sorts() {
+        let compressor = CoordinateCompressor::new(&tiv![(50, 70), (10, 50), (10, 20)]);
+        assert_eq!(compressor.values(), &[10, 20, 50, 70]);
+        assert_eq!(compressor.rank(&50), Some(2));
+        assert_eq!(compressor.rank(&60), None);
+        assert_eq!(compressor.value(0), Some(10));
+        assert_eq!(compressor.value(4), None);
+    }
+
+    #[test]
+    fn bounds_between_values() {
+        let compressor = CoordinateCompressor::new(&tiv![(10, 20), (50, 70)]);
+        assert_eq!(compressor.lower_bound(&5), 0);
+        assert_eq!(compressor.lower_bound(&20), 1);
+        assert_eq!(compressor.upper_bound(&20), 2);
+        assert_eq!(compressor.lower_bound(&30), 2);
+        assert_eq!(compressor.upper_bound(&99), 4);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let intervals = tiv![(50, 70), (10, 50), (15, 20)];
+        let compressor = CoordinateCompressor::new(&intervals);
+        let compressed = compressor.compress_all(&intervals).unwrap();
+        assert_eq!(
+            compressed,
+            [(3, 4), (0, 3), (1, 2)]
+                .into_iter()
+                .map(Interval::from)
+                .collect::<Vec<_>>()
+        );
+        let decompressed: Vec<_> = compressed
+            .iter()
+            .map(|interval| compressor.decompress(interval))
+            .collect();
+        assert_eq!(decompressed, intervals);
+    }
+
+    #[test]
+    fn compress_unknown() {
+        let compressor = CoordinateCompressor::new(&tiv![(10, 20)]);
+        assert_eq!(compressor.compress(&(10, 30).into()), None);
+        assert_eq!(compressor.compress_all(&tiv![(10, 20), (10, 30)]), None);
+    }
+}
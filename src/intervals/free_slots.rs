@@ -0,0 +1,319 @@
+use alloc::vec::Vec;
+use core::cmp::max;
+use core::ops::{Add, Rem, Sub};
+
+use super::{merge_2_interval_iters, Interval};
+
+/// Find the earliest slot of length `duration` inside `window` that doesn't overlap any busy
+/// [Interval].
+///
+/// If `alignment` is given, the slot starts at a multiple of it. An alignment of 0 is the same as
+/// none.
+///
+/// # Complexity:
+///
+/// | Space | Runtime |
+/// |-------|---------|
+/// | O(1)  | O(n)    |
+///
+/// Where n = len(busy)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, find_free_slot};
+///
+/// let busy = vec![Interval {start: 9, end: 10}, Interval {start: 11, end: 13}];
+/// let window = Interval {start: 8, end: 18};
+///
+/// assert_eq!(find_free_slot(&busy, 2, window, None), Some(Interval {start: 13, end: 15}));
+/// assert_eq!(find_free_slot(&busy, 1, window, None), Some(Interval {start: 8, end: 9}));
+/// assert_eq!(find_free_slot(&busy, 2, window, Some(4)), Some(Interval {start: 16, end: 18}));
+/// ```
+///
+/// assumptions:
+/// Vec is sorted and non-overlapping.
+pub fn find_free_slot<T>(
+    busy: &[Interval<T>],
+    duration: T,
+    window: Interval<T>,
+    alignment: Option<T>,
+) -> Option<Interval<T>>
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+{
+    find_free_slot_with_buffer(busy, duration, window, alignment, T::default())
+}
+
+/// Same as <code>[find_free_slot]</code>, but keeps at least `buffer` free time between the slot
+/// and any busy [Interval].
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, find_free_slot_with_buffer};
+///
+/// let busy = vec![Interval {start: 9, end: 10}, Interval {start: 14, end: 15}];
+/// let window = Interval {start: 8, end: 18};
+///
+/// assert_eq!(
+///     find_free_slot_with_buffer(&busy, 2, window, None, 1),
+///     Some(Interval {start: 11, end: 13})
+/// );
+/// ```
+///
+/// assumptions:
+/// Vec is sorted and non-overlapping.
+pub fn find_free_slot_with_buffer<T>(
+    busy: &[Interval<T>],
+    duration: T,
+    window: Interval<T>,
+    alignment: Option<T>,
+    buffer: T,
+) -> Option<Interval<T>>
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+{
+    let align = |t: T| match alignment {
+        Some(alignment) => align_up(t, alignment),
+        None => t,
+    };
+
+    let mut start = align(window.start);
+    for block in busy {
+        if start + duration > window.end {
+            return None;
+        }
+        if block.end + buffer <= start {
+            continue;
+        }
+        if start + duration + buffer <= block.start {
+            break;
+        }
+        start = align(max(start, block.end + buffer));
+    }
+
+    if start + duration <= window.end {
+        Some(Interval {
+            start,
+            end: start + duration,
+        })
+    } else {
+        None
+    }
+}
+
+/// Get every free [Interval] inside `window` that is at least `duration` long.
+///
+/// # Complexity:
+///
+/// | Space | Runtime |
+/// |-------|---------|
+/// | O(n)  | O(n)    |
+///
+/// Where n = len(busy)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, free_slots};
+///
+/// let busy = vec![Interval {start: 9, end: 10}, Interval {start: 11, end: 13}];
+///
+/// assert_eq!(
+///     free_slots(&busy, 2, Interval {start: 8, end: 18}),
+///     vec![Interval {start: 13, end: 18}]
+/// );
+/// ```
+///
+/// assumptions:
+/// Vec is sorted and non-overlapping.
+pub fn free_slots<T>(busy: &[Interval<T>], duration: T, window: Interval<T>) -> Vec<Interval<T>>
+where
+    T: Copy + Ord + Sub<Output = T>,
+{
+    let mut output = Vec::new();
+    let mut cursor = window.start;
+    for block in busy {
+        if block.start >= window.end {
+            break;
+        }
+        if block.start > cursor && block.start - cursor >= duration {
+            output.push(Interval {
+                start: cursor,
+                end: block.start,
+            });
+        }
+        cursor = max(cursor, block.end);
+    }
+    if window.end > cursor && window.end - cursor >= duration {
+        output.push(Interval {
+            start: cursor,
+            end: window.end,
+        });
+    }
+
+    output
+}
+
+/// Find the earliest slot that is free in all calendars.
+///
+/// See <code>[find_free_slot]</code>.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(n)  | O(n * k) |
+///
+/// Where n = total number of busy intervals, k = len(calendars)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, find_common_free_slot};
+///
+/// let alice = vec![Interval {start: 9, end: 10}];
+/// let bob = vec![Interval {start: 10, end: 12}];
+///
+/// assert_eq!(
+///     find_common_free_slot(&[alice, bob], 1, Interval {start: 9, end: 17}, None),
+///     Some(Interval {start: 12, end: 13})
+/// );
+/// ```
+///
+/// assumptions:
+/// Every calendar is sorted and non-overlapping.
+pub fn find_common_free_slot<T, C>(
+    calendars: &[C],
+    duration: T,
+    window: Interval<T>,
+    alignment: Option<T>,
+) -> Option<Interval<T>>
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+    C: AsRef<[Interval<T>]>,
+{
+    let busy = calendars.iter().fold(Vec::new(), |busy, calendar| {
+        merge_2_interval_iters(&busy, calendar.as_ref())
+    });
+    find_free_slot(&busy, duration, window, alignment)
+}
+
+/// Round `t` up to the next multiple of `alignment`, or leave it as is if `alignment` is 0.
+fn align_up<T>(t: T, alignment: T) -> T
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+{
+    let zero = T::default();
+    if alignment == zero {
+        return t;
+    }
+    let rem = t % alignment;
+    if rem == zero {
+        t
+    } else if rem < zero {
+        t - rem
+    } else {
+        t + (alignment - rem)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intervals::testing::tiv;
+
+    #[test]
+    fn empty_calendar() {
+        let busy = tiv![];
+        let result = find_free_slot(&busy, 3, (2, 10).into(), None);
+        assert_eq!(result, Some((2, 5).into()));
+    }
+
+    #[test]
+    fn slot_between_blocks() {
+        let busy = tiv![(0, 3), (5, 6), (8, 12)];
+        let result = find_free_slot(&busy, 2, (0, 20).into(), None);
+        assert_eq!(result, Some((3, 5).into()));
+    }
+
+    #[test]
+    fn slot_after_last_block() {
+        let busy = tiv![(0, 3), (5, 6), (8, 12)];
+        let result = find_free_slot(&busy, 3, (0, 20).into(), None);
+        assert_eq!(result, Some((12, 15).into()));
+    }
+
+    #[test]
+    fn no_slot_in_window() {
+        let busy = tiv![(0, 3), (5, 6), (8, 12)];
+        let result = find_free_slot(&busy, 3, (0, 14).into(), None);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn window_starts_inside_block() {
+        let busy = tiv![(0, 3), (5, 6)];
+        let result = find_free_slot(&busy, 1, (1, 10).into(), None);
+        assert_eq!(result, Some((3, 4).into()));
+    }
+
+    #[test]
+    fn aligned() {
+        let busy = tiv![(0, 3), (5, 6)];
+        let result = find_free_slot(&busy, 2, (0, 20).into(), Some(5));
+        assert_eq!(result, Some((10, 12).into()));
+    }
+
+    #[test]
+    fn aligned_negative() {
+        let busy = [Interval { start: -9, end: -4 }];
+        let result = find_free_slot(&busy, 2, (-10, 20).into(), Some(4));
+        assert_eq!(result, Some((-4, -2).into()));
+    }
+
+    #[test]
+    fn zero_alignment_is_unaligned() {
+        let busy = tiv![(0, 3), (5, 6)];
+        let result = find_free_slot(&busy, 2, (0, 20).into(), Some(0));
+        assert_eq!(result, Some((3, 5).into()));
+    }
+
+    #[test]
+    fn buffer_around_blocks() {
+        let busy = tiv![(2, 4), (7, 9)];
+        let result = find_free_slot_with_buffer(&busy, 2, (0, 20).into(), None, 1);
+        assert_eq!(result, Some((10, 12).into()));
+    }
+
+    #[test]
+    fn buffer_before_window_end() {
+        let busy = tiv![(10, 12)];
+        let result = find_free_slot_with_buffer(&busy, 2, (0, 9).into(), None, 1);
+        assert_eq!(result, Some((0, 2).into()));
+        let result = find_free_slot_with_buffer(&busy, 2, (8, 10).into(), None, 1);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn free_slots_in_window() {
+        let busy = tiv![(0, 3), (5, 6), (8, 12), (14, 30)];
+        let result = free_slots(&busy, 2, (1, 16).into());
+        assert_eq!(result, tiv![(3, 5), (6, 8), (12, 14)]);
+    }
+
+    #[test]
+    fn free_slots_too_short() {
+        let busy = tiv![(0, 3), (5, 6), (8, 12)];
+        let result = free_slots(&busy, 3, (0, 13).into());
+        assert_eq!(result, tiv![]);
+    }
+
+    #[test]
+    fn common_slot() {
+        let calendars = [tiv![(0, 3), (8, 10)], tiv![(2, 5)], tiv![(6, 7)]];
+        let result = find_common_free_slot(&calendars, 2, (0, 20).into(), None);
+        assert_eq!(result, Some((10, 12).into()));
+    }
+}
@@ -0,0 +1,108 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::max;
+
+use super::Interval;
+
+/// Group [Interval]s that overlap, directly or through other [Interval]s, without merging them.
+///
+/// Returns every group's hull, which is what <code>[merge_overlapping]</code> would have merged
+/// the group into, together with the indices of its members in `intervals`. Groups are ordered by
+/// start, and so are the members of each group. [Interval]s that touch are grouped together, same
+/// as in <code>[merge_overlapping]</code>.
+///
+/// [merge_overlapping]: super::merge_overlapping
+///
+/// # Complexity:
+///
+/// | Space | Runtime      |
+/// |-------|--------------|
+/// | O(n)  | O(n * log n) |
+///
+/// Where n = len(intervals)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, group_overlapping};
+///
+/// let intervals = [Interval {start: 4, end: 7}, Interval {start: 8, end: 10}, Interval {start: 1, end: 5}];
+/// assert_eq!(
+///     vec![
+///         (Interval {start: 1, end: 7}, vec![2, 0]),
+///         (Interval {start: 8, end: 10}, vec![1]),
+///     ],
+///     group_overlapping(&intervals)
+/// );
+/// ```
+pub fn group_overlapping<T>(intervals: &[Interval<T>]) -> Vec<(Interval<T>, Vec<usize>)>
+where
+    T: Copy + Ord,
+{
+    let mut order: Vec<usize> = (0..intervals.len()).collect();
+    order.sort_by_key(|&i| (intervals[i].start, intervals[i].end));
+
+    let mut output: Vec<(Interval<T>, Vec<usize>)> = Vec::new();
+    for i in order {
+        let current = intervals[i];
+        match output.last_mut() {
+            Some((hull, members)) if current.start <= hull.end => {
+                hull.end = max(hull.end, current.end);
+                members.push(i);
+            }
+            _ => output.push((current, vec![i])),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intervals::testing::tiv;
+
+    #[test]
+    fn empty() {
+        assert_eq!(group_overlapping(&tiv![]), vec![]);
+    }
+
+    #[test]
+    fn disjoint() {
+        let result = group_overlapping(&tiv![(5, 7), (1, 3)]);
+        assert_eq!(
+            result,
+            vec![((1, 3).into(), vec![1]), ((5, 7).into(), vec![0])]
+        );
+    }
+
+    #[test]
+    fn transitive_overlaps() {
+        let result = group_overlapping(&tiv![(1, 3), (6, 9), (2, 5), (4, 7), (20, 21)]);
+        assert_eq!(
+            result,
+            vec![
+                ((1, 9).into(), vec![0, 2, 3, 1]),
+                ((20, 21).into(), vec![4])
+            ]
+        );
+    }
+
+    #[test]
+    fn touching_and_duplicates() {
+        let result = group_overlapping(&tiv![(3, 5), (1, 3), (1, 3)]);
+        assert_eq!(result, vec![((1, 5).into(), vec![1, 2, 0])]);
+    }
+
+    #[test]
+    fn hulls_match_merge_overlapping() {
+        let intervals = tiv![(12, 20), (2, 3), (5, 6), (8, 11), (7, 8), (19, 25)];
+        let mut merged = intervals.clone();
+        crate::intervals::sort_and_merge_overlapping(&mut merged);
+        let hulls: Vec<_> = group_overlapping(&intervals)
+            .into_iter()
+            .map(|(hull, _)| hull)
+            .collect();
+        assert_eq!(hulls, merged);
+    }
+}
@@ -3,13 +3,51 @@ use core::cmp::{max, min};
 use alloc::vec::Vec;
 
 use super::{sort_by_start, Interval};
+use crate::search::partition_point;
 
-// Uses binary search
-pub fn insert_interval_and_merge_pre_sorted<T>(_input: &mut Vec<Interval<T>>, _new: Interval<T>)
-where
+/// Insert `new` into sorted, non overlapping `intervals`, merging it with the ones it overlaps.
+///
+/// Finds the overlapping run with two binary searches, so only the splice is linear.
+/// [Interval]s that touch are merged, same as in
+/// <code>[insert_interval_and_merge_pre_sorted_linear]</code>.
+///
+/// # Complexity:
+///
+/// | Space | Runtime |
+/// |-------|---------|
+/// | O(1)  | O(n)    |
+///
+/// Where n = len(intervals)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, insert_interval_and_merge_pre_sorted};
+///
+/// let mut vec = vec![Interval {start: 1, end: 2}, Interval {start: 3, end: 4}, Interval {start: 6, end: 7}];
+/// insert_interval_and_merge_pre_sorted(&mut vec, Interval {start: 4, end: 5});
+/// assert_eq!(
+///     vec![Interval {start: 1, end: 2}, Interval {start: 3, end: 5}, Interval {start: 6, end: 7}],
+///     vec
+/// );
+/// ```
+///
+/// assumptions:
+/// `intervals` is sorted by start, and has no overlaps.
+pub fn insert_interval_and_merge_pre_sorted<T>(
+    intervals: &mut Vec<Interval<T>>,
+    mut new: Interval<T>,
+) where
     T: Copy + Ord,
 {
-    todo!()
+    // Without overlaps the ends are sorted too, so both searches are valid.
+    let first = partition_point(intervals, |current| current.end < new.start);
+    let last = partition_point(intervals, |current| current.start <= new.end);
+    if first < last {
+        new.start = min(new.start, intervals[first].start);
+        new.end = max(new.end, intervals[last - 1].end);
+    }
+    intervals.splice(first..last, [new]);
 }
 
 /// # Complexity:
@@ -147,6 +185,7 @@ mod tests {
 
     use super::*;
     use crate::intervals::testing::tiv;
+    use crate::testing::lcg;
 
     #[test]
     fn simple_a() {
@@ -175,6 +214,30 @@ mod tests {
         assert_eq!(tiv![(4, 8)], vec);
     }
 
+    #[test]
+    fn binary_search_matches_linear() {
+        let mut seed = 71;
+        let mut next = || lcg(&mut seed) as u32;
+        for _ in 0..300 {
+            let mut vec = tiv![];
+            let mut start = next() % 4;
+            for _ in 0..next() % 8 {
+                let end = start + next() % 4;
+                vec.push(Interval { start, end });
+                start = end + 1 + next() % 4;
+            }
+            let start = next() % 40;
+            let new = Interval {
+                start,
+                end: start + next() % 10,
+            };
+            let mut expected = vec.clone();
+            insert_interval_and_merge_pre_sorted_linear(&mut expected, new);
+            insert_interval_and_merge_pre_sorted(&mut vec, new);
+            assert_eq!(vec, expected);
+        }
+    }
+
     #[test]
     fn unsorted() {
         let mut vec = tiv![(12, 20), (2, 3), (5, 10)];
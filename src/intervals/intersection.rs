@@ -1,24 +1,34 @@
 use alloc::vec::Vec;
 use core::cmp::{max, min};
 
-use super::Interval;
+use super::{Interval, GALLOP_RATIO};
+use crate::search::gallop_partition_point;
 
+/// Intersect two lists of [Interval]s. [Interval]s that only touch don't intersect.
+///
+/// When one list is much longer than the other, gallops through the longer one with
+/// <code>[gallop_partition_point]</code>, skipping the [Interval]s that can't intersect. That
+/// takes O(m * log(n / m)) for m much smaller than n, like a small query set against a huge
+/// track.
+///
 /// Complexity:
-/// Runtime: O(n + m)
-/// where n = a.len() and m = b.len()
+/// Runtime: O(n + m), or O(m * log(n / m) + k) when galloping
+/// where n = a.len(), m = b.len(), k = number of intersections, n >= m
 ///
 /// space: O(n + m)
 /// where n = a.len() and m = b.len()
 ///
 /// assumptions:
 /// Vec is sorted and non-overlapping.
-pub fn intersection_2_interval_iters<T>(
-    a: &Vec<Interval<T>>,
-    b: &Vec<Interval<T>>,
-) -> Vec<Interval<T>>
+pub fn intersection_2_interval_iters<T>(a: &[Interval<T>], b: &[Interval<T>]) -> Vec<Interval<T>>
 where
     T: Copy + Ord,
 {
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    if short.len().saturating_mul(GALLOP_RATIO) <= long.len() {
+        return intersection_galloping(short, long);
+    }
+
     let mut output = Vec::new();
     let mut ai = 0;
     let mut bi = 0;
@@ -43,6 +53,31 @@ where
     output
 }
 
+fn intersection_galloping<T>(short: &[Interval<T>], long: &[Interval<T>]) -> Vec<Interval<T>>
+where
+    T: Copy + Ord,
+{
+    let mut output = Vec::new();
+    let mut li = 0;
+    for interval in short {
+        // Without overlaps the ends are sorted too, so this skips everything before interval.
+        li += gallop_partition_point(&long[li..], |current| current.end <= interval.start);
+        while let Some(current) = long.get(li).filter(|current| current.start < interval.end) {
+            output.push(Interval {
+                start: max(current.start, interval.start),
+                end: min(current.end, interval.end),
+            });
+            if current.end > interval.end {
+                // current may also intersect the next interval.
+                break;
+            }
+            li += 1;
+        }
+    }
+
+    output
+}
+
 pub fn intersection_interval_iters<T>(_input: &Vec<Vec<Interval<T>>>) -> Vec<Interval<T>>
 where
     T: Copy + Ord,
@@ -53,7 +88,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::intervals::testing::tiv;
+    use crate::intervals::testing::{random_disjoint, tiv};
+    use crate::testing::lcg;
 
     #[test]
     fn empty_intervals() {
@@ -117,4 +153,29 @@ mod tests {
         let result = intersection_2_interval_iters(&a, &b);
         assert_eq!(result, tiv![]);
     }
+
+    #[test]
+    fn galloping_matches_brute_force() {
+        let mut seed = 89;
+        for _ in 0..300 {
+            let short_len = lcg(&mut seed) as usize % 5;
+            let short = random_disjoint(&mut seed, short_len, 80);
+            let long_len = lcg(&mut seed) as usize % 200;
+            let long = random_disjoint(&mut seed, long_len, 3);
+            let mut expected = Vec::new();
+            for x in &short {
+                for y in &long {
+                    if max(x.start, y.start) < min(x.end, y.end) {
+                        expected.push(Interval {
+                            start: max(x.start, y.start),
+                            end: min(x.end, y.end),
+                        });
+                    }
+                }
+            }
+            expected.sort_by_key(|interval| interval.start);
+            assert_eq!(intersection_2_interval_iters(&short, &long), expected);
+            assert_eq!(intersection_2_interval_iters(&long, &short), expected);
+        }
+    }
 }
@@ -0,0 +1,405 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::ds::MinHeap;
+
+use super::Interval;
+
+/// Get the indices of a largest set of [Interval]s that all overlap each other.
+///
+/// These are the [Interval]s over the deepest point, so there are
+/// <code>[max_overlaps_depth]</code> of them. [Interval]s that touch overlap. Indices are sorted.
+///
+/// [max_overlaps_depth]: super::max_overlaps_depth
+///
+/// # Complexity:
+///
+/// | Space | Runtime      |
+/// |-------|--------------|
+/// | O(n)  | O(n * log n) |
+///
+/// Where n = len(intervals)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, max_clique};
+///
+/// let intervals = [
+///     Interval {start: 1, end: 4},
+///     Interval {start: 6, end: 9},
+///     Interval {start: 3, end: 7},
+///     Interval {start: 4, end: 5},
+/// ];
+/// assert_eq!(max_clique(&intervals), vec![0, 2, 3]);
+/// ```
+pub fn max_clique<T>(intervals: &[Interval<T>]) -> Vec<usize>
+where
+    T: Copy + Ord,
+{
+    // At the same point, starts go before ends, so touching intervals are counted together.
+    let mut events: Vec<(T, bool)> = intervals
+        .iter()
+        .flat_map(|interval| [(interval.start, false), (interval.end, true)])
+        .collect();
+    events.sort_unstable();
+
+    let mut depth = 0;
+    let mut deepest: Option<(usize, T)> = None;
+    for (point, is_end) in events {
+        if is_end {
+            depth -= 1;
+            continue;
+        }
+        depth += 1;
+        if deepest.map(|(max_depth, _)| max_depth) < Some(depth) {
+            deepest = Some((depth, point));
+        }
+    }
+
+    let Some((_, point)) = deepest else {
+        return Vec::new();
+    };
+    (0..intervals.len())
+        .filter(|&i| intervals[i].start <= point && point <= intervals[i].end)
+        .collect()
+}
+
+/// Get the indices of a largest set of [Interval]s where no two overlap.
+///
+/// Greedily takes the [Interval] that ends first among the ones that don't overlap the ones
+/// already taken. [Interval]s that touch overlap. Indices are ordered by end.
+///
+/// # Complexity:
+///
+/// | Space | Runtime      |
+/// |-------|--------------|
+/// | O(n)  | O(n * log n) |
+///
+/// Where n = len(intervals)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, max_independent_set};
+///
+/// let intervals = [
+///     Interval {start: 1, end: 10},
+///     Interval {start: 5, end: 6},
+///     Interval {start: 1, end: 3},
+///     Interval {start: 6, end: 8},
+/// ];
+/// assert_eq!(max_independent_set(&intervals), vec![2, 1]);
+/// ```
+pub fn max_independent_set<T>(intervals: &[Interval<T>]) -> Vec<usize>
+where
+    T: Copy + Ord,
+{
+    let mut chosen: Vec<usize> = Vec::new();
+    for i in by_end(intervals) {
+        if chosen.last().map(|&last| intervals[last].end) < Some(intervals[i].start) {
+            chosen.push(i);
+        }
+    }
+    chosen
+}
+
+/// Split the [Interval]s into the fewest groups where all [Interval]s of a group overlap each
+/// other.
+///
+/// Every group contains the end of its first [Interval], and these first [Interval]s are
+/// exactly <code>[max_independent_set]</code>, which shows no cover can be smaller. [Interval]s
+/// that touch overlap. Groups are ordered by the end of their first [Interval], and the indices in
+/// a group by end.
+///
+/// # Complexity:
+///
+/// | Space | Runtime      |
+/// |-------|--------------|
+/// | O(n)  | O(n * log n) |
+///
+/// Where n = len(intervals)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, min_clique_cover};
+///
+/// let intervals = [
+///     Interval {start: 1, end: 10},
+///     Interval {start: 5, end: 6},
+///     Interval {start: 1, end: 3},
+///     Interval {start: 6, end: 8},
+/// ];
+/// assert_eq!(min_clique_cover(&intervals), vec![vec![2], vec![1, 3, 0]]);
+/// ```
+pub fn min_clique_cover<T>(intervals: &[Interval<T>]) -> Vec<Vec<usize>>
+where
+    T: Copy + Ord,
+{
+    let mut cover: Vec<Vec<usize>> = Vec::new();
+    let mut point = None;
+    for i in by_end(intervals) {
+        // Intervals come by end, so the ones starting before the point all contain it.
+        match (point, cover.last_mut()) {
+            (Some(point), Some(group)) if intervals[i].start <= point => group.push(i),
+            _ => {
+                point = Some(intervals[i].end);
+                cover.push(vec![i]);
+            }
+        }
+    }
+    cover
+}
+
+/// Color the [Interval]s so that overlapping [Interval]s get different colors, using as few
+/// colors as possible.
+///
+/// Returns the color of every [Interval], counting from 0. The number of colors, the chromatic
+/// number, is the size of <code>[max_clique]</code>. [Interval]s that touch overlap.
+///
+/// # Complexity:
+///
+/// | Space | Runtime      |
+/// |-------|--------------|
+/// | O(n)  | O(n * log n) |
+///
+/// Where n = len(intervals)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, color_intervals};
+///
+/// let intervals = [
+///     Interval {start: 1, end: 4},
+///     Interval {start: 2, end: 3},
+///     Interval {start: 5, end: 8},
+///     Interval {start: 4, end: 6},
+/// ];
+/// assert_eq!(color_intervals(&intervals), vec![0, 1, 0, 1]);
+/// ```
+pub fn color_intervals<T>(intervals: &[Interval<T>]) -> Vec<usize>
+where
+    T: Copy + Ord,
+{
+    let mut order: Vec<usize> = (0..intervals.len()).collect();
+    order.sort_by_key(|&i| intervals[i].start);
+
+    let mut colors = vec![0; intervals.len()];
+    let mut color_count = 0;
+    let mut in_use: MinHeap<(T, usize)> = MinHeap::new();
+    let mut free: MinHeap<usize> = MinHeap::new();
+    for i in order {
+        while let Some(&(end, color)) = in_use.peek() {
+            if end >= intervals[i].start {
+                break;
+            }
+            in_use.pop();
+            free.push(color);
+        }
+        let color = free.pop().unwrap_or_else(|| {
+            color_count += 1;
+            color_count - 1
+        });
+        colors[i] = color;
+        in_use.push((intervals[i].end, color));
+    }
+    colors
+}
+
+/// Get the edges of the interval graph, where two [Interval]s are connected when they overlap.
+///
+/// Yields every overlapping pair once, as `(i, j)` with `i < j`, by sweeping the [Interval]s by
+/// start. [Interval]s that touch overlap.
+///
+/// # Complexity:
+///
+/// | Space | Runtime          |
+/// |-------|------------------|
+/// | O(n)  | O(n * log n + k) |
+///
+/// Where n = len(intervals), k = number of edges
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, overlap_edges};
+///
+/// let intervals = [
+///     Interval {start: 1, end: 4},
+///     Interval {start: 6, end: 8},
+///     Interval {start: 3, end: 6},
+/// ];
+/// assert_eq!(overlap_edges(&intervals).collect::<Vec<_>>(), vec![(0, 2), (1, 2)]);
+/// ```
+pub fn overlap_edges<T>(intervals: &[Interval<T>]) -> OverlapEdges<'_, T>
+where
+    T: Copy + Ord,
+{
+    let mut order: Vec<usize> = (0..intervals.len()).collect();
+    order.sort_by_key(|&i| intervals[i].start);
+    OverlapEdges {
+        intervals,
+        order,
+        next: 0,
+        active: Vec::new(),
+        pending: 0,
+    }
+}
+
+/// Iterator over the overlapping pairs of [Interval]s, returned by <code>[overlap_edges]</code>.
+#[derive(Clone, Debug)]
+pub struct OverlapEdges<'a, T>
+where
+    T: Copy + Ord,
+{
+    intervals: &'a [Interval<T>],
+    order: Vec<usize>,
+    /// Position in `order` of the next [Interval] to sweep.
+    next: usize,
+    /// Swept [Interval]s that may still overlap later ones.
+    active: Vec<usize>,
+    /// Number of `active` [Interval]s still to pair with the last swept one.
+    pending: usize,
+}
+
+impl<T> Iterator for OverlapEdges<'_, T>
+where
+    T: Copy + Ord,
+{
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending == 0 {
+            let &current = self.order.get(self.next)?;
+            self.next += 1;
+            let start = self.intervals[current].start;
+            let intervals = self.intervals;
+            self.active.retain(|&i| intervals[i].end >= start);
+            self.pending = self.active.len();
+            self.active.push(current);
+        }
+        self.pending -= 1;
+        let current = *self.active.last().unwrap();
+        let other = self.active[self.pending];
+        Some((other.min(current), other.max(current)))
+    }
+}
+
+/// Indices of `intervals`, ordered by end.
+fn by_end<T>(intervals: &[Interval<T>]) -> Vec<usize>
+where
+    T: Copy + Ord,
+{
+    let mut order: Vec<usize> = (0..intervals.len()).collect();
+    order.sort_by_key(|&i| intervals[i].end);
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intervals::max_overlaps_depth;
+    use crate::intervals::testing::tiv;
+    use crate::testing::lcg;
+
+    fn overlap(a: &Interval<u32>, b: &Interval<u32>) -> bool {
+        a.start <= b.end && b.start <= a.end
+    }
+
+    fn random_intervals(seed: &mut u64, n: usize) -> Vec<Interval<u32>> {
+        (0..n)
+            .map(|_| {
+                let start = lcg(seed) as u32 % 50;
+                Interval {
+                    start,
+                    end: start + lcg(seed) as u32 % 8,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn empty() {
+        let intervals = tiv![];
+        assert!(max_clique(&intervals).is_empty());
+        assert!(max_independent_set(&intervals).is_empty());
+        assert!(min_clique_cover(&intervals).is_empty());
+        assert!(color_intervals(&intervals).is_empty());
+        assert_eq!(overlap_edges(&intervals).next(), None);
+    }
+
+    #[test]
+    fn touching_overlap() {
+        let intervals = tiv![(1, 3), (3, 5), (5, 7)];
+        assert_eq!(max_clique(&intervals), vec![0, 1]);
+        assert_eq!(max_independent_set(&intervals), vec![0, 2]);
+        assert_eq!(min_clique_cover(&intervals), vec![vec![0, 1], vec![2]]);
+        assert_eq!(color_intervals(&intervals), vec![0, 1, 0]);
+        assert_eq!(
+            overlap_edges(&intervals).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut seed = 61;
+        for _ in 0..200 {
+            let n = lcg(&mut seed) as usize % 30;
+            let intervals = random_intervals(&mut seed, n);
+
+            let mut expected_edges = Vec::new();
+            for i in 0..n {
+                for j in i + 1..n {
+                    if overlap(&intervals[i], &intervals[j]) {
+                        expected_edges.push((i, j));
+                    }
+                }
+            }
+            let mut edges: Vec<_> = overlap_edges(&intervals).collect();
+            edges.sort_unstable();
+            assert_eq!(edges, expected_edges);
+
+            let clique = max_clique(&intervals);
+            let mut sorted = intervals.clone();
+            sorted.sort_by_key(|interval| interval.start);
+            assert_eq!(clique.len() as u32, max_overlaps_depth(&sorted));
+            for (k, &i) in clique.iter().enumerate() {
+                for &j in &clique[k + 1..] {
+                    assert!(overlap(&intervals[i], &intervals[j]));
+                }
+            }
+
+            let independent = max_independent_set(&intervals);
+            for (k, &i) in independent.iter().enumerate() {
+                for &j in &independent[k + 1..] {
+                    assert!(!overlap(&intervals[i], &intervals[j]));
+                }
+            }
+
+            let cover = min_clique_cover(&intervals);
+            assert_eq!(cover.len(), independent.len());
+            let mut covered: Vec<usize> = cover.iter().flatten().copied().collect();
+            covered.sort_unstable();
+            assert_eq!(covered, (0..n).collect::<Vec<_>>());
+            for group in &cover {
+                for (k, &i) in group.iter().enumerate() {
+                    for &j in &group[k + 1..] {
+                        assert!(overlap(&intervals[i], &intervals[j]));
+                    }
+                }
+            }
+
+            let colors = color_intervals(&intervals);
+            assert_eq!(
+                colors.iter().map(|&color| color + 1).max().unwrap_or(0),
+                clique.len()
+            );
+            for &(i, j) in &expected_edges {
+                assert_ne!(colors[i], colors[j]);
+            }
+        }
+    }
+}
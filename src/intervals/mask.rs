@@ -0,0 +1,224 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::min;
+
+use super::Interval;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Get the runs of `true` in a mask as [Interval]s.
+///
+/// # Complexity:
+///
+/// | Space | Runtime |
+/// |-------|---------|
+/// | O(r)  | O(n)    |
+///
+/// Where n = len(mask), r = number of runs
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, mask_to_intervals};
+///
+/// assert_eq!(
+///     vec![Interval {start: 1, end: 3}, Interval {start: 4, end: 5}],
+///     mask_to_intervals(&[false, true, true, false, true])
+/// );
+/// ```
+pub fn mask_to_intervals(mask: &[bool]) -> Vec<Interval<usize>> {
+    let mut output = Vec::new();
+    let mut run_start = None;
+    for (i, &set) in mask.iter().enumerate() {
+        match (set, run_start) {
+            (true, None) => run_start = Some(i),
+            (false, Some(start)) => {
+                output.push(Interval { start, end: i });
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = run_start {
+        output.push(Interval {
+            start,
+            end: mask.len(),
+        });
+    }
+
+    output
+}
+
+/// Get a mask of length `len` that is `true` inside the [Interval]s.
+///
+/// Anything past `len` is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, intervals_to_mask};
+///
+/// assert_eq!(
+///     vec![false, true, true, false, true],
+///     intervals_to_mask(&[Interval {start: 1, end: 3}, Interval {start: 4, end: 9}], 5)
+/// );
+/// ```
+pub fn intervals_to_mask(intervals: &[Interval<usize>], len: usize) -> Vec<bool> {
+    let mut mask = vec![false; len];
+    for interval in intervals {
+        let end = min(interval.end, len);
+        if interval.start < end {
+            mask[interval.start..end].fill(true);
+        }
+    }
+    mask
+}
+
+/// Get the runs of set bits in a bitset as [Interval]s.
+///
+/// Bit `i` is bit `i % 64` of `words[i / 64]`. Runs are found a word at a time, so long runs of
+/// set or unset bits are cheap.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(r)  | O(w + r) |
+///
+/// Where w = len(words), r = number of runs
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, bitset_to_intervals};
+///
+/// assert_eq!(
+///     vec![Interval {start: 0, end: 2}, Interval {start: 60, end: 70}],
+///     bitset_to_intervals(&[0b11 | (0b1111 << 60), 0b11_1111])
+/// );
+/// ```
+pub fn bitset_to_intervals(words: &[u64]) -> Vec<Interval<usize>> {
+    let mut output = Vec::new();
+    let total = words.len() * WORD_BITS;
+    let mut pos = 0;
+    while pos < total {
+        let word = words[pos / WORD_BITS] >> (pos % WORD_BITS);
+        if word == 0 {
+            pos = (pos / WORD_BITS + 1) * WORD_BITS;
+            continue;
+        }
+        pos += word.trailing_zeros() as usize;
+
+        let start = pos;
+        while pos < total {
+            let offset = pos % WORD_BITS;
+            let ones = (words[pos / WORD_BITS] >> offset).trailing_ones() as usize;
+            pos += ones;
+            if offset + ones < WORD_BITS {
+                break;
+            }
+        }
+        output.push(Interval { start, end: pos });
+    }
+
+    output
+}
+
+/// Get a bitset of `len` bits that is set inside the [Interval]s.
+///
+/// See <code>[bitset_to_intervals]</code> for the layout. Anything past `len` is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, intervals_to_bitset};
+///
+/// assert_eq!(
+///     vec![0b11 | (0b1111 << 60), 0b11_1111],
+///     intervals_to_bitset(&[Interval {start: 0, end: 2}, Interval {start: 60, end: 70}], 128)
+/// );
+/// ```
+pub fn intervals_to_bitset(intervals: &[Interval<usize>], len: usize) -> Vec<u64> {
+    let mut words = vec![0_u64; len.div_ceil(WORD_BITS)];
+    for interval in intervals {
+        let end = min(interval.end, len);
+        let mut pos = interval.start;
+        while pos < end {
+            let offset = pos % WORD_BITS;
+            let count = min(WORD_BITS - offset, end - pos);
+            let bits = if count == WORD_BITS {
+                u64::MAX
+            } else {
+                ((1_u64 << count) - 1) << offset
+            };
+            words[pos / WORD_BITS] |= bits;
+            pos += count;
+        }
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iv(start: usize, end: usize) -> Interval<usize> {
+        Interval { start, end }
+    }
+
+    #[test]
+    fn mask_empty() {
+        assert_eq!(mask_to_intervals(&[]), vec![]);
+        assert_eq!(mask_to_intervals(&[false, false]), vec![]);
+    }
+
+    #[test]
+    fn mask_all_set() {
+        assert_eq!(mask_to_intervals(&[true, true, true]), vec![iv(0, 3)]);
+    }
+
+    #[test]
+    fn mask_runs() {
+        let mask = [true, false, true, true, false, false, true];
+        let intervals = mask_to_intervals(&mask);
+        assert_eq!(intervals, vec![iv(0, 1), iv(2, 4), iv(6, 7)]);
+        assert_eq!(intervals_to_mask(&intervals, mask.len()), mask);
+    }
+
+    #[test]
+    fn bitset_empty() {
+        assert_eq!(bitset_to_intervals(&[]), vec![]);
+        assert_eq!(bitset_to_intervals(&[0, 0, 0]), vec![]);
+    }
+
+    #[test]
+    fn bitset_full_words() {
+        assert_eq!(bitset_to_intervals(&[u64::MAX, u64::MAX]), vec![iv(0, 128)]);
+        assert_eq!(
+            bitset_to_intervals(&[0, u64::MAX, 0, 1]),
+            vec![iv(64, 128), iv(192, 193)]
+        );
+    }
+
+    #[test]
+    fn bitset_run_across_words() {
+        let words = [1 << 63, u64::MAX, 0b111];
+        assert_eq!(bitset_to_intervals(&words), vec![iv(63, 131)]);
+        assert_eq!(intervals_to_bitset(&[iv(63, 131)], 192), words);
+    }
+
+    #[test]
+    fn bitset_matches_mask() {
+        let mask: Vec<bool> = (0..300)
+            .map(|i| i % 7 < 3 || (100..180).contains(&i))
+            .collect();
+        let intervals = mask_to_intervals(&mask);
+        let words = intervals_to_bitset(&intervals, mask.len());
+        assert_eq!(bitset_to_intervals(&words), intervals);
+    }
+
+    #[test]
+    fn bitset_ignores_past_len() {
+        assert_eq!(intervals_to_bitset(&[iv(2, 100)], 4), vec![0b1100]);
+    }
+}
@@ -1,13 +1,22 @@
 use alloc::vec::Vec;
 use core::cmp::max;
 
-use super::Interval;
+use super::{Interval, GALLOP_RATIO};
+use crate::ds::{MinHeap, MinPriorityQueue};
+use crate::search::gallop_partition_point;
 
+/// Merge two lists of [Interval]s into one, merging the ones that overlap or touch.
+///
+/// When one list is much longer than the other, gallops through it with
+/// <code>[gallop_partition_point]</code> to find the runs between the [Interval]s of the shorter
+/// one. That takes O(a * log(b / a)) comparisons for a much shorter than b, instead of O(a + b),
+/// though copying the runs is still O(b).
+///
 /// # Complexity:
 ///
-/// | Space    | Runtime              |
-/// |----------|----------------------|
-/// | O(a + b) | O(a + b)             |
+/// | Space    | Runtime  |
+/// |----------|----------|
+/// | O(a + b) | O(a + b) |
 ///
 /// Where a = len(a), b = len(b)
 ///
@@ -78,71 +87,194 @@ use super::Interval;
 /// # }
 ///
 /// ```
-
-pub fn merge_2_interval_iters<'a, I, T>(a: I, b: I) -> Vec<Interval<T>>
+///
+/// assumptions:
+/// Both lists are sorted and non-overlapping.
+pub fn merge_2_interval_iters<T>(a: &[Interval<T>], b: &[Interval<T>]) -> Vec<Interval<T>>
 where
-    I: IntoIterator<Item = &'a Interval<T>>,
-    T: Copy + Ord + 'a,
+    T: Copy + Ord,
 {
-    let mut a_iter = a.into_iter();
-    let mut b_iter = b.into_iter();
-    let mut output: Vec<Interval<T>> = Vec::new();
-    let mut a_next = a_iter.next();
-    let mut b_next = b_iter.next();
-    while let (Some(a), Some(b)) = (a_next, b_next) {
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    if short.len().saturating_mul(GALLOP_RATIO) <= long.len() {
+        return merge_galloping(short, long);
+    }
+
+    let mut output: Vec<Interval<T>> = Vec::with_capacity(a.len() + b.len());
+    let mut ai = 0;
+    let mut bi = 0;
+    while ai < a.len() && bi < b.len() {
         match output.last_mut() {
-            Some(last) if a.start <= last.end => {
-                last.end = max(last.end, a.end);
-                a_next = a_iter.next();
+            Some(last) if a[ai].start <= last.end => {
+                last.end = max(last.end, a[ai].end);
+                ai += 1;
             }
-            Some(last) if b.start <= last.end => {
-                last.end = max(last.end, b.end);
-                b_next = b_iter.next();
+            Some(last) if b[bi].start <= last.end => {
+                last.end = max(last.end, b[bi].end);
+                bi += 1;
             }
             _ => {
-                if a.start < b.start {
-                    output.push(*a);
-                    a_next = a_iter.next();
+                if a[ai].start < b[bi].start {
+                    output.push(a[ai]);
+                    ai += 1;
                 } else {
-                    output.push(*b);
-                    b_next = b_iter.next();
+                    output.push(b[bi]);
+                    bi += 1;
                 }
             }
         }
     }
-    while let Some(a) = a_next {
-        match output.last_mut() {
-            Some(last) if a.start <= last.end => {
-                last.end = max(last.end, a.end);
-            }
-            _ => output.push(*a),
-        }
-        a_next = a_iter.next();
+    for current in a[ai..].iter().chain(&b[bi..]) {
+        push_merged(&mut output, *current);
     }
-    while let Some(b) = b_next {
-        match output.last_mut() {
-            Some(last) if b.start <= last.end => {
-                last.end = max(last.end, b.end);
-            }
-            _ => output.push(*b),
+
+    output
+}
+
+fn merge_galloping<T>(short: &[Interval<T>], long: &[Interval<T>]) -> Vec<Interval<T>>
+where
+    T: Copy + Ord,
+{
+    let mut output: Vec<Interval<T>> = Vec::with_capacity(short.len() + long.len());
+    let mut li = 0;
+    for interval in short {
+        // The run of the long list before this interval. Its [Interval]s can still touch each
+        // other, so they're merged one by one.
+        let run = gallop_partition_point(&long[li..], |current| current.start < interval.start);
+        for current in &long[li..li + run] {
+            push_merged(&mut output, *current);
+        }
+        li += run;
+        push_merged(&mut output, *interval);
+
+        // The run of the long list that this interval overlaps. Without overlaps the ends are
+        // sorted too, so only the last can end after it.
+        let last = output.last_mut().unwrap();
+        let covered = gallop_partition_point(&long[li..], |current| current.start <= last.end);
+        if covered > 0 {
+            last.end = max(last.end, long[li + covered - 1].end);
         }
-        b_next = b_iter.next();
+        li += covered;
+    }
+    for current in &long[li..] {
+        push_merged(&mut output, *current);
     }
 
     output
 }
 
-pub fn merge_interval_iters<T>(_input: &Vec<Vec<Interval<T>>>) -> Vec<Interval<T>>
+/// Push `interval` after the sorted `output`, merging it into the last [Interval] if they overlap.
+fn push_merged<T>(output: &mut Vec<Interval<T>>, interval: Interval<T>)
+where
+    T: Copy + Ord,
+{
+    match output.last_mut() {
+        Some(last) if interval.start <= last.end => {
+            last.end = max(last.end, interval.end);
+        }
+        _ => output.push(interval),
+    }
+}
+
+/// Merge any number of [Interval] lists, with a k-way merge.
+///
+/// # Complexity:
+///
+/// | Space    | Runtime          |
+/// |----------|------------------|
+/// | O(n + k) | O(n * log k)     |
+///
+/// Where n = total number of [Interval]s, k = len(input)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, merge_interval_iters};
+///
+/// let result = merge_interval_iters(&[
+///     vec![Interval {start: 1, end: 3}, Interval {start: 9, end: 12}],
+///     vec![Interval {start: 2, end: 5}],
+///     vec![Interval {start: 7, end: 8}],
+/// ]);
+/// assert_eq!(
+///     vec![Interval {start: 1, end: 5}, Interval {start: 7, end: 8}, Interval {start: 9, end: 12}],
+///     result
+/// );
+/// ```
+///
+/// assumptions:
+/// Every Vec is sorted and non-overlapping.
+pub fn merge_interval_iters<T>(input: &[Vec<Interval<T>>]) -> Vec<Interval<T>>
 where
     T: Copy + Ord,
 {
-    todo!()
+    merge_interval_iters_with(input, MinHeap::new())
+}
+
+/// <code>[merge_interval_iters]</code> with any <code>[MinPriorityQueue]</code>, for example a
+/// <code>[DaryHeap]</code> when there are many lists.
+///
+/// The queue holds `(start, list index, index in list)` for the next [Interval] of every list,
+/// and should be empty.
+///
+/// [DaryHeap]: crate::ds::DaryHeap
+///
+/// # Complexity:
+///
+/// | Space    | Runtime          |
+/// |----------|------------------|
+/// | O(n + k) | O(n * log k)     |
+///
+/// Where n = total number of [Interval]s, k = len(input), for a binary heap
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::ds::PairingHeap;
+/// use kupsy::intervals::{Interval, merge_interval_iters_with};
+///
+/// let result = merge_interval_iters_with(
+///     &[vec![Interval {start: 1, end: 3}], vec![Interval {start: 3, end: 4}]],
+///     PairingHeap::new(),
+/// );
+/// assert_eq!(vec![Interval {start: 1, end: 4}], result);
+/// ```
+///
+/// assumptions:
+/// Every Vec is sorted and non-overlapping.
+pub fn merge_interval_iters_with<T, Q>(input: &[Vec<Interval<T>>], mut queue: Q) -> Vec<Interval<T>>
+where
+    T: Copy + Ord,
+    Q: MinPriorityQueue<Item = (T, usize, usize)>,
+{
+    for (list, intervals) in input.iter().enumerate() {
+        if let Some(first) = intervals.first() {
+            queue.push((first.start, list, 0));
+        }
+    }
+
+    let mut output: Vec<Interval<T>> = Vec::new();
+    while let Some((_, list, index)) = queue.pop() {
+        let current = input[list][index];
+        match output.last_mut() {
+            Some(last) if current.start <= last.end => {
+                last.end = max(last.end, current.end);
+            }
+            _ => output.push(current),
+        }
+        if let Some(next) = input[list].get(index + 1) {
+            queue.push((next.start, list, index + 1));
+        }
+    }
+
+    output
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::intervals::testing::tiv;
+    use crate::ds::{DaryHeap, PairingHeap};
+    use crate::intervals::testing::{random_disjoint, tiv};
+    use crate::testing::lcg;
 
     #[test]
     fn test_empty_vecs() {
@@ -191,4 +323,58 @@ mod tests {
         let expected = tiv![(1, 7)];
         assert_eq!(merge_2_interval_iters(&a, &b), expected);
     }
+
+    #[test]
+    fn test_galloping_merges_touching() {
+        let long: Vec<_> = (0..16)
+            .map(|i| Interval::from((2 * i, 2 * i + 2)))
+            .collect();
+        let short = tiv![(100, 101)];
+        let expected = tiv![(0, 32), (100, 101)];
+        assert_eq!(merge_2_interval_iters(&long, &short), expected);
+        assert_eq!(merge_2_interval_iters(&short, &long), expected);
+    }
+
+    #[test]
+    fn test_k_way_empty() {
+        assert_eq!(merge_interval_iters::<u32>(&[]), tiv![]);
+        assert_eq!(merge_interval_iters(&[tiv![], tiv![]]), tiv![]);
+    }
+
+    #[test]
+    fn test_k_way_matches_pairwise() {
+        let input = [
+            tiv![(1, 3), (10, 12), (20, 25)],
+            tiv![(2, 4), (12, 14)],
+            tiv![],
+            tiv![(5, 6), (8, 9), (14, 19)],
+        ];
+        let pairwise = input
+            .iter()
+            .fold(tiv![], |acc, next| merge_2_interval_iters(&acc, next));
+        assert_eq!(pairwise, tiv![(1, 4), (5, 6), (8, 9), (10, 19), (20, 25)]);
+        assert_eq!(merge_interval_iters(&input), pairwise);
+        assert_eq!(
+            merge_interval_iters_with(&input, DaryHeap::<_, 4>::new()),
+            pairwise
+        );
+        assert_eq!(
+            merge_interval_iters_with(&input, PairingHeap::new()),
+            pairwise
+        );
+    }
+
+    #[test]
+    fn test_galloping_matches_k_way() {
+        let mut seed = 83;
+        for _ in 0..300 {
+            let short_len = lcg(&mut seed) as usize % 5;
+            let short = random_disjoint(&mut seed, short_len, 60);
+            let long_len = lcg(&mut seed) as usize % 200;
+            let long = random_disjoint(&mut seed, long_len, 4);
+            let expected = merge_interval_iters(&[short.clone(), long.clone()]);
+            assert_eq!(merge_2_interval_iters(&short, &long), expected);
+            assert_eq!(merge_2_interval_iters(&long, &short), expected);
+        }
+    }
 }
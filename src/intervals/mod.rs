@@ -1,25 +1,44 @@
 use core::ops::Sub;
 
+mod compress;
+mod free_slots;
+mod group;
 mod insert;
 mod intersection;
+mod interval_graph;
+mod mask;
 mod merge_iters;
 mod merge_overlapping;
+mod morphology;
+mod nearest;
 mod overlaps;
+mod rle;
 mod sizes;
 mod sort;
 #[cfg(test)]
 mod testing;
 mod vec;
 
+pub use compress::*;
+pub use free_slots::*;
+pub use group::*;
 pub use insert::*;
 pub use intersection::*;
+pub use interval_graph::*;
+pub use mask::*;
 pub use merge_iters::*;
 pub use merge_overlapping::*;
+pub use morphology::*;
+pub use nearest::*;
 pub use overlaps::*;
+pub use rle::*;
 pub use sizes::*;
 pub use sort::*;
 pub use vec::*;
 
+/// How many times longer one list must be than the other for merging and intersecting to gallop.
+const GALLOP_RATIO: usize = 8;
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct Interval<T>
 where
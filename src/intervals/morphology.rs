@@ -0,0 +1,339 @@
+use alloc::vec::Vec;
+use core::cmp::max;
+use core::ops::{Add, Sub};
+
+use super::{merge_overlapping, sort_by_start, Interval};
+
+/// Merge [Interval]s that overlap, or that are separated by a gap of at most `max_gap`.
+///
+/// Works like a morphological closing of the set: small holes are filled, and everything else is
+/// left as is. With a `max_gap` of zero it's the same as <code>[merge_overlapping]</code>.
+///
+/// # Complexity:
+///
+/// | Space | Runtime |
+/// |-------|---------|
+/// | O(1)  | O(n)    |
+///
+/// Where n = len(input)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, merge_within};
+///
+/// let mut input = vec![Interval {start: 1, end: 3}, Interval {start: 4, end: 6}, Interval {start: 9, end: 10}];
+/// merge_within(&mut input, 1);
+/// assert_eq!(
+///     vec![Interval {start: 1, end: 6}, Interval {start: 9, end: 10}],
+///     input
+/// );
+/// ```
+///
+/// assumptions:
+/// Vec is sorted by Interval.start.
+pub fn merge_within<T>(input: &mut Vec<Interval<T>>, max_gap: T)
+where
+    T: Copy + Ord + Add<Output = T>,
+{
+    if input.is_empty() {
+        return;
+    }
+
+    let mut last_valid: usize = 0;
+    for i in 1..input.len() {
+        let current = input[i];
+        let last = &mut input[last_valid];
+        if current.start <= last.end + max_gap {
+            last.end = max(last.end, current.end);
+        } else {
+            last_valid += 1;
+            input[last_valid] = current;
+        }
+    }
+    input.truncate(last_valid + 1);
+}
+
+/// Sort, and merge [Interval]s that are at most `max_gap` apart.
+///
+/// Combination of <code>[sort_by_start]</code> and <code>[merge_within]</code>.
+pub fn sort_and_merge_within<T>(input: &mut Vec<Interval<T>>, max_gap: T)
+where
+    T: Copy + Ord + Add<Output = T>,
+{
+    sort_by_start(input);
+    merge_within(input, max_gap)
+}
+
+/// Remove every [Interval] that is shorter than `min_len`.
+///
+/// Works like a morphological opening of the set: slivers are removed, and everything else is
+/// left as is.
+///
+/// # Complexity:
+///
+/// | Space | Runtime |
+/// |-------|---------|
+/// | O(1)  | O(n)    |
+///
+/// Where n = len(input)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, drop_shorter_than};
+///
+/// let mut input = vec![Interval {start: 1, end: 6}, Interval {start: 8, end: 9}];
+/// drop_shorter_than(&mut input, 2);
+/// assert_eq!(vec![Interval {start: 1, end: 6}], input);
+/// ```
+pub fn drop_shorter_than<T>(input: &mut Vec<Interval<T>>, min_len: T)
+where
+    T: Copy + Ord + Sub<Output = T>,
+{
+    input.retain(|interval| interval.length() >= min_len)
+}
+
+/// Integer types that <code>[dilate]</code> can pad.
+pub trait SaturatingSub: Copy {
+    /// `self - other`, or the smallest value of the type if that would underflow.
+    fn saturating_sub(self, other: Self) -> Self;
+}
+
+macro_rules! impl_saturating_sub {
+    ($($t:ty),*) => {
+        $(
+            impl SaturatingSub for $t {
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$t>::saturating_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_sub!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Grow every [Interval] by `pad_before` and `pad_after`, and merge the ones that now overlap.
+///
+/// Starts stop at the smallest value of the type instead of underflowing, like 0 for unsigned
+/// types.
+///
+/// # Complexity:
+///
+/// | Space | Runtime |
+/// |-------|---------|
+/// | O(n)  | O(n)    |
+///
+/// Where n = len(intervals)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, dilate};
+///
+/// let intervals = [Interval {start: 10, end: 12}, Interval {start: 15, end: 16}, Interval {start: 30, end: 31}];
+/// assert_eq!(
+///     vec![Interval {start: 8, end: 17}, Interval {start: 28, end: 32}],
+///     dilate(&intervals, 2, 1)
+/// );
+/// ```
+///
+/// assumptions:
+/// Slice is sorted and non-overlapping.
+pub fn dilate<T>(intervals: &[Interval<T>], pad_before: T, pad_after: T) -> Vec<Interval<T>>
+where
+    T: Copy + Ord + Add<Output = T> + SaturatingSub,
+{
+    let mut output = intervals
+        .iter()
+        .map(|interval| Interval {
+            start: interval.start.saturating_sub(pad_before),
+            end: interval.end + pad_after,
+        })
+        .collect();
+    merge_overlapping(&mut output);
+    output
+}
+
+/// Shrink every [Interval] by `shrink_before` and `shrink_after`, and remove the ones that
+/// collapse.
+///
+/// # Complexity:
+///
+/// | Space | Runtime |
+/// |-------|---------|
+/// | O(n)  | O(n)    |
+///
+/// Where n = len(intervals)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, erode};
+///
+/// let intervals = [Interval {start: 10, end: 20}, Interval {start: 25, end: 27}];
+/// assert_eq!(vec![Interval {start: 12, end: 19}], erode(&intervals, 2, 1));
+/// ```
+///
+/// assumptions:
+/// Slice is sorted and non-overlapping.
+pub fn erode<T>(intervals: &[Interval<T>], shrink_before: T, shrink_after: T) -> Vec<Interval<T>>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    intervals
+        .iter()
+        .filter(|interval| interval.start + shrink_before + shrink_after < interval.end)
+        .map(|interval| Interval {
+            start: interval.start + shrink_before,
+            end: interval.end - shrink_after,
+        })
+        .collect()
+}
+
+/// Move every [Interval] forward by `offset`.
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, shift};
+///
+/// let mut intervals = [Interval {start: 1, end: 3}, Interval {start: 5, end: 6}];
+/// shift(&mut intervals, 10);
+/// assert_eq!([Interval {start: 11, end: 13}, Interval {start: 15, end: 16}], intervals);
+/// ```
+pub fn shift<T>(intervals: &mut [Interval<T>], offset: T)
+where
+    T: Copy + Ord + Add<Output = T>,
+{
+    for interval in intervals {
+        interval.start = interval.start + offset;
+        interval.end = interval.end + offset;
+    }
+}
+
+/// Move every [Interval] back by `offset`.
+///
+/// Same as <code>[shift]</code>, for types that can't hold a negative offset.
+pub fn shift_back<T>(intervals: &mut [Interval<T>], offset: T)
+where
+    T: Copy + Ord + Sub<Output = T>,
+{
+    for interval in intervals {
+        interval.start = interval.start - offset;
+        interval.end = interval.end - offset;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intervals::testing::tiv;
+
+    #[test]
+    fn merge_within_empty() {
+        let mut input = tiv![];
+        merge_within(&mut input, 3);
+        assert_eq!(input, tiv![]);
+    }
+
+    #[test]
+    fn merge_within_zero_gap() {
+        let mut input = tiv![(1, 3), (3, 4), (5, 7)];
+        merge_within(&mut input, 0);
+        assert_eq!(input, tiv![(1, 4), (5, 7)]);
+    }
+
+    #[test]
+    fn merge_within_bridges_small_gaps() {
+        let mut input = tiv![(1, 3), (5, 7), (10, 11), (12, 20), (25, 26)];
+        merge_within(&mut input, 2);
+        assert_eq!(input, tiv![(1, 7), (10, 20), (25, 26)]);
+    }
+
+    #[test]
+    fn merge_within_nested() {
+        let mut input = tiv![(1, 10), (2, 3), (11, 12)];
+        merge_within(&mut input, 1);
+        assert_eq!(input, tiv![(1, 12)]);
+    }
+
+    #[test]
+    fn merge_within_unsorted() {
+        let mut input = tiv![(10, 11), (1, 3), (5, 7)];
+        sort_and_merge_within(&mut input, 2);
+        assert_eq!(input, tiv![(1, 7), (10, 11)]);
+    }
+
+    #[test]
+    fn drop_shorter_than_removes_slivers() {
+        let mut input = tiv![(1, 2), (3, 6), (7, 7), (8, 10)];
+        drop_shorter_than(&mut input, 2);
+        assert_eq!(input, tiv![(3, 6), (8, 10)]);
+    }
+
+    #[test]
+    fn closing_then_opening() {
+        let mut input = tiv![(0, 4), (5, 9), (20, 21), (30, 35), (36, 37)];
+        merge_within(&mut input, 1);
+        drop_shorter_than(&mut input, 3);
+        assert_eq!(input, tiv![(0, 9), (30, 37)]);
+    }
+
+    #[test]
+    fn dilate_merges_neighbors() {
+        let input = tiv![(10, 12), (15, 16), (20, 21), (40, 50)];
+        assert_eq!(dilate(&input, 1, 2), tiv![(9, 18), (19, 23), (39, 52)]);
+    }
+
+    #[test]
+    fn dilate_touching_neighbors() {
+        let input = tiv![(10, 12), (14, 16)];
+        assert_eq!(dilate(&input, 1, 1), tiv![(9, 17)]);
+    }
+
+    #[test]
+    fn dilate_stops_at_zero() {
+        let input = tiv![(0, 2), (3, 4), (10, 12)];
+        assert_eq!(dilate(&input, 5, 1), tiv![(0, 13)]);
+    }
+
+    #[test]
+    fn dilate_signed() {
+        let input = [
+            Interval { start: -3, end: -1 },
+            Interval { start: 2, end: 4 },
+        ];
+        assert_eq!(dilate(&input, 3, 0), [Interval { start: -6, end: 4 }]);
+        let input = [Interval { start: 1, end: 2 }];
+        assert_eq!(erode(&dilate(&input, 3, 1), 3, 1), input);
+    }
+
+    #[test]
+    fn dilate_empty() {
+        assert_eq!(dilate(&tiv![], 1, 1), tiv![]);
+    }
+
+    #[test]
+    fn erode_drops_collapsed() {
+        let input = tiv![(10, 20), (25, 28), (30, 33), (40, 41)];
+        assert_eq!(erode(&input, 1, 1), tiv![(11, 19), (26, 27), (31, 32)]);
+        assert_eq!(erode(&input, 2, 1), tiv![(12, 19)]);
+    }
+
+    #[test]
+    fn erode_undoes_dilate() {
+        let input = tiv![(10, 12), (20, 25)];
+        assert_eq!(erode(&dilate(&input, 3, 2), 3, 2), input);
+    }
+
+    #[test]
+    fn shift_and_back() {
+        let mut input = tiv![(10, 12), (20, 25)];
+        shift(&mut input, 5);
+        assert_eq!(input, tiv![(15, 17), (25, 30)]);
+        shift_back(&mut input, 15);
+        assert_eq!(input, tiv![(0, 2), (10, 15)]);
+    }
+}
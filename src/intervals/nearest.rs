@@ -0,0 +1,278 @@
+use alloc::vec::Vec;
+use core::ops::Sub;
+
+use super::Interval;
+
+/// Find the [Interval] closest to `t`.
+///
+/// Returns its index and its distance from `t`. The distance is zero when `t` is inside the
+/// [Interval]. Ties go to the earlier [Interval].
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log n) |
+///
+/// Where n = len(intervals)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, nearest_interval};
+///
+/// let intervals = [Interval {start: 1, end: 3}, Interval {start: 10, end: 12}];
+/// assert_eq!(Some((0, 0)), nearest_interval(&intervals, 2));
+/// assert_eq!(Some((1, 2)), nearest_interval(&intervals, 8));
+/// assert_eq!(None, nearest_interval::<u32>(&[], 8));
+/// ```
+///
+/// assumptions:
+/// Slice is sorted and non-overlapping.
+pub fn nearest_interval<T>(intervals: &[Interval<T>], t: T) -> Option<(usize, T)>
+where
+    T: Copy + Ord + Default + Sub<Output = T>,
+{
+    nearest_by(intervals.len(), |i| intervals[i], t)
+}
+
+/// Find the `k` [Interval]s closest to `t`, closest first.
+///
+/// See <code>[nearest_interval]</code>.
+///
+/// # Complexity:
+///
+/// | Space | Runtime      |
+/// |-------|--------------|
+/// | O(k)  | O(log n + k) |
+///
+/// Where n = len(intervals)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, k_nearest_intervals};
+///
+/// let intervals = [
+///     Interval {start: 1, end: 3},
+///     Interval {start: 10, end: 12},
+///     Interval {start: 14, end: 15},
+/// ];
+/// assert_eq!(vec![(1, 2), (0, 5)], k_nearest_intervals(&intervals, 8, 2));
+/// ```
+///
+/// assumptions:
+/// Slice is sorted and non-overlapping.
+pub fn k_nearest_intervals<T>(intervals: &[Interval<T>], t: T, k: usize) -> Vec<(usize, T)>
+where
+    T: Copy + Ord + Default + Sub<Output = T>,
+{
+    let mut output = Vec::with_capacity(k.min(intervals.len()));
+    // Distances only grow when walking away from this point, in either direction.
+    let middle = intervals.partition_point(|interval| interval.end < t);
+    let mut left = middle;
+    let mut right = middle;
+    while output.len() < k {
+        let before = left.checked_sub(1).map(|i| (i, distance(&intervals[i], t)));
+        let after = intervals
+            .get(right)
+            .map(|interval| (right, distance(interval, t)));
+        match (before, after) {
+            (Some(before), Some(after)) if before.1 <= after.1 => {
+                output.push(before);
+                left -= 1;
+            }
+            (_, Some(after)) => {
+                output.push(after);
+                right += 1;
+            }
+            (Some(before), None) => {
+                output.push(before);
+                left -= 1;
+            }
+            (None, None) => break,
+        }
+    }
+    output
+}
+
+/// Find the free gap that is closest to `t`, which says when the next free moment is.
+///
+/// Returns the gap's index, and its distance from `t`. Gap `i` is the free time before
+/// [Interval] `i`, so gap 0 is the unbounded one before the first [Interval], gap `len` is the
+/// unbounded one after the last, and gap `i` in between is gap `i - 1` of
+/// <code>[get_gaps_intervals]</code>. If `t` is outside every [Interval], the distance is 0.
+///
+/// [get_gaps_intervals]: super::get_gaps_intervals
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log n) |
+///
+/// Where n = len(intervals)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, nearest_gap};
+///
+/// let busy = [Interval {start: 1, end: 3}, Interval {start: 4, end: 9}, Interval {start: 10, end: 12}];
+/// assert_eq!((1, 0), nearest_gap(&busy, 3));
+/// assert_eq!((2, 2), nearest_gap(&busy, 7));
+/// assert_eq!((0, 0), nearest_gap(&busy, 0));
+/// assert_eq!((3, 0), nearest_gap(&busy, 15));
+/// ```
+///
+/// assumptions:
+/// Slice is sorted and non-overlapping.
+pub fn nearest_gap<T>(intervals: &[Interval<T>], t: T) -> (usize, T)
+where
+    T: Copy + Ord + Default + Sub<Output = T>,
+{
+    let (Some(first), Some(last)) = (intervals.first(), intervals.last()) else {
+        return (0, T::default());
+    };
+    if t <= first.start {
+        return (0, T::default());
+    }
+    if t >= last.end {
+        return (intervals.len(), T::default());
+    }
+    // t is inside the busy span, so the unbounded gaps only need their inner end.
+    let gap = |i: usize| Interval {
+        start: if i == 0 {
+            first.start
+        } else {
+            intervals[i - 1].end
+        },
+        end: if i == intervals.len() {
+            last.end
+        } else {
+            intervals[i].start
+        },
+    };
+    nearest_by(intervals.len() + 1, gap, t).unwrap()
+}
+
+fn nearest_by<T, F>(len: usize, get: F, t: T) -> Option<(usize, T)>
+where
+    T: Copy + Ord + Default + Sub<Output = T>,
+    F: Fn(usize) -> Interval<T>,
+{
+    // First interval that doesn't end before t.
+    let mut left = 0;
+    let mut right = len;
+    while left < right {
+        let middle = left + (right - left) / 2;
+        if get(middle).end < t {
+            left = middle + 1;
+        } else {
+            right = middle;
+        }
+    }
+
+    let before = left.checked_sub(1).map(|i| (i, distance(&get(i), t)));
+    let after = (left < len).then(|| (left, distance(&get(left), t)));
+    match (before, after) {
+        (Some(before), Some(after)) if after.1 < before.1 => Some(after),
+        (Some(before), _) => Some(before),
+        (None, after) => after,
+    }
+}
+
+fn distance<T>(interval: &Interval<T>, t: T) -> T
+where
+    T: Copy + Ord + Default + Sub<Output = T>,
+{
+    if t < interval.start {
+        interval.start - t
+    } else if t > interval.end {
+        t - interval.end
+    } else {
+        T::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intervals::testing::tiv;
+    use alloc::vec;
+
+    #[test]
+    fn nearest_empty() {
+        assert_eq!(nearest_interval(&tiv![], 5), None);
+        assert_eq!(k_nearest_intervals(&tiv![], 5, 3), vec![]);
+        assert_eq!(nearest_gap(&tiv![], 5), (0, 0));
+    }
+
+    #[test]
+    fn nearest_inside() {
+        let intervals = tiv![(1, 3), (5, 8), (20, 30)];
+        assert_eq!(nearest_interval(&intervals, 6), Some((1, 0)));
+        assert_eq!(nearest_interval(&intervals, 8), Some((1, 0)));
+        assert_eq!(nearest_interval(&intervals, 20), Some((2, 0)));
+    }
+
+    #[test]
+    fn nearest_outside() {
+        let intervals = tiv![(1, 3), (5, 8), (20, 30)];
+        assert_eq!(nearest_interval(&intervals, 0), Some((0, 1)));
+        assert_eq!(nearest_interval(&intervals, 13), Some((1, 5)));
+        assert_eq!(nearest_interval(&intervals, 15), Some((2, 5)));
+        assert_eq!(nearest_interval(&intervals, 40), Some((2, 10)));
+    }
+
+    #[test]
+    fn nearest_tie_goes_to_earlier() {
+        let intervals = tiv![(1, 3), (5, 8)];
+        assert_eq!(nearest_interval(&intervals, 4), Some((0, 1)));
+    }
+
+    #[test]
+    fn k_nearest() {
+        let intervals = tiv![(1, 3), (5, 8), (20, 30), (31, 32)];
+        assert_eq!(
+            k_nearest_intervals(&intervals, 10, 3),
+            vec![(1, 2), (0, 7), (2, 10)]
+        );
+        assert_eq!(
+            k_nearest_intervals(&intervals, 10, 10),
+            vec![(1, 2), (0, 7), (2, 10), (3, 21)]
+        );
+    }
+
+    #[test]
+    fn k_nearest_zero() {
+        assert_eq!(k_nearest_intervals(&tiv![(1, 3)], 10, 0), vec![]);
+    }
+
+    #[test]
+    fn gap() {
+        let intervals = tiv![(1, 3), (5, 8), (20, 30)];
+        assert_eq!(nearest_gap(&intervals, 4), (1, 0));
+        assert_eq!(nearest_gap(&intervals, 9), (2, 0));
+        assert_eq!(nearest_gap(&intervals, 7), (2, 1));
+        assert_eq!(nearest_gap(&intervals, 27), (3, 3));
+    }
+
+    #[test]
+    fn gap_before_first() {
+        let intervals = tiv![(10, 20), (22, 30)];
+        assert_eq!(nearest_gap(&intervals, 0), (0, 0));
+        assert_eq!(nearest_gap(&intervals, 10), (0, 0));
+        // Closer to the start of the first interval than to the gap after it.
+        assert_eq!(nearest_gap(&intervals, 12), (0, 2));
+    }
+
+    #[test]
+    fn gap_after_last() {
+        let intervals = tiv![(10, 20), (22, 30)];
+        assert_eq!(nearest_gap(&intervals, 30), (2, 0));
+        assert_eq!(nearest_gap(&intervals, 100), (2, 0));
+        assert_eq!(nearest_gap(&intervals, 27), (2, 3));
+        assert_eq!(nearest_gap(&tiv![(1, 2)], 5), (1, 0));
+    }
+}
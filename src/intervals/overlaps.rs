@@ -1,6 +1,6 @@
 use alloc::vec::Vec;
 
-use crate::ds::MinHeap;
+use crate::ds::MinHeapByKey;
 
 use super::Interval;
 
@@ -46,14 +46,15 @@ pub fn max_overlaps_depth<T>(intervals: &Vec<Interval<T>>) -> u32
 where
     T: Copy + Ord,
 {
-    let mut heap = MinHeap::new();
+    let mut heap = MinHeapByKey::new(|interval: &Interval<T>| interval.end);
     for interval in intervals {
-        if let Some(first_to_finish) = heap.peek() {
-            if first_to_finish < &interval.start {
-                heap.pop();
-            }
+        if heap
+            .peek()
+            .is_some_and(|first_to_finish| first_to_finish.end < interval.start)
+        {
+            heap.pop();
         }
-        heap.push(interval.end);
+        heap.push(*interval);
     }
 
     heap.len() as u32
@@ -0,0 +1,207 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use super::Interval;
+
+/// Run-length encode a sequence.
+///
+/// Every run of equal values becomes one `(Interval, value)` pair, where the [Interval] holds the
+/// indices of the run.
+///
+/// # Complexity:
+///
+/// | Space | Runtime |
+/// |-------|---------|
+/// | O(r)  | O(n)    |
+///
+/// Where n = len(iter), r = number of runs
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, rle_encode};
+///
+/// assert_eq!(
+///     vec![(Interval {start: 0, end: 2}, 'a'), (Interval {start: 2, end: 3}, 'b')],
+///     rle_encode("aab".chars())
+/// );
+/// ```
+pub fn rle_encode<I, V>(iter: I) -> Vec<(Interval<usize>, V)>
+where
+    I: IntoIterator<Item = V>,
+    V: PartialEq,
+{
+    let mut output: Vec<(Interval<usize>, V)> = Vec::new();
+    for (i, value) in iter.into_iter().enumerate() {
+        match output.last_mut() {
+            Some((run, last)) if *last == value => run.end = i + 1,
+            _ => output.push((
+                Interval {
+                    start: i,
+                    end: i + 1,
+                },
+                value,
+            )),
+        }
+    }
+    output
+}
+
+/// Expand runs back into the sequence they were encoded from.
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{rle_decode, rle_encode};
+///
+/// let input = vec![1, 1, 1, 4, 4, 1];
+/// assert_eq!(input, rle_decode(&rle_encode(input.clone())));
+/// ```
+///
+/// assumptions:
+/// Runs are sorted, and cover every index from 0 without gaps.
+pub fn rle_decode<V>(runs: &[(Interval<usize>, V)]) -> Vec<V>
+where
+    V: Clone,
+{
+    let mut output = Vec::with_capacity(runs.last().map_or(0, |(run, _)| run.end));
+    for (run, value) in runs {
+        output.resize(output.len() + run.length(), value.clone());
+    }
+    output
+}
+
+/// Merge runs that touch, and hold equal values.
+///
+/// Useful after building runs by hand, or after concatenating encodings.
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{Interval, merge_equal_runs};
+///
+/// let mut runs = vec![
+///     (Interval {start: 0, end: 2}, 'a'),
+///     (Interval {start: 2, end: 3}, 'a'),
+///     (Interval {start: 3, end: 4}, 'b'),
+/// ];
+/// merge_equal_runs(&mut runs);
+/// assert_eq!(
+///     vec![(Interval {start: 0, end: 3}, 'a'), (Interval {start: 3, end: 4}, 'b')],
+///     runs
+/// );
+/// ```
+///
+/// assumptions:
+/// Runs are sorted and non-overlapping.
+pub fn merge_equal_runs<V>(runs: &mut Vec<(Interval<usize>, V)>)
+where
+    V: PartialEq,
+{
+    runs.dedup_by(|(run, value), (last_run, last_value)| {
+        if last_run.end == run.start && last_value == value {
+            last_run.end = run.end;
+            true
+        } else {
+            false
+        }
+    })
+}
+
+/// Get the value at `index`, using binary search.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log r) |
+///
+/// Where r = len(runs)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::{rle_encode, rle_lookup};
+///
+/// let runs = rle_encode("aaabbc".chars());
+/// assert_eq!(Some(&'b'), rle_lookup(&runs, 4));
+/// assert_eq!(None, rle_lookup(&runs, 6));
+/// ```
+///
+/// assumptions:
+/// Runs are sorted and non-overlapping.
+pub fn rle_lookup<V>(runs: &[(Interval<usize>, V)], index: usize) -> Option<&V> {
+    runs.binary_search_by(|(run, _)| {
+        if run.end <= index {
+            Ordering::Less
+        } else if run.start > index {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    })
+    .ok()
+    .map(|i| &runs[i].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn iv(start: usize, end: usize) -> Interval<usize> {
+        Interval { start, end }
+    }
+
+    #[test]
+    fn encode_empty() {
+        let runs = rle_encode(Vec::<u8>::new());
+        assert!(runs.is_empty());
+        assert_eq!(rle_decode(&runs), vec![]);
+    }
+
+    #[test]
+    fn encode_single_run() {
+        let runs = rle_encode([7, 7, 7]);
+        assert_eq!(runs, vec![(iv(0, 3), 7)]);
+    }
+
+    #[test]
+    fn encode_alternating() {
+        let runs = rle_encode([1, 2, 1, 2]);
+        assert_eq!(
+            runs,
+            vec![(iv(0, 1), 1), (iv(1, 2), 2), (iv(2, 3), 1), (iv(3, 4), 2)]
+        );
+    }
+
+    #[test]
+    fn decode_roundtrip() {
+        let input = vec![0, 0, 3, 3, 3, 3, 0, 5, 5];
+        assert_eq!(rle_decode(&rle_encode(input.clone())), input);
+    }
+
+    #[test]
+    fn merge_keeps_gaps() {
+        let mut runs = vec![(iv(0, 2), 1), (iv(3, 4), 1), (iv(4, 6), 1), (iv(6, 7), 2)];
+        merge_equal_runs(&mut runs);
+        assert_eq!(runs, vec![(iv(0, 2), 1), (iv(3, 6), 1), (iv(6, 7), 2)]);
+    }
+
+    #[test]
+    fn lookup() {
+        let runs = rle_encode([1, 1, 2, 3, 3, 3]);
+        let decoded = rle_decode(&runs);
+        for (i, value) in decoded.iter().enumerate() {
+            assert_eq!(rle_lookup(&runs, i), Some(value));
+        }
+        assert_eq!(rle_lookup(&runs, 6), None);
+    }
+
+    #[test]
+    fn lookup_in_gap() {
+        let runs = vec![(iv(0, 2), 'a'), (iv(5, 6), 'b')];
+        assert_eq!(rle_lookup(&runs, 3), None);
+        assert_eq!(rle_lookup(&runs, 5), Some(&'b'));
+    }
+}
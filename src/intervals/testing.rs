@@ -1,3 +1,8 @@
+use alloc::vec::Vec;
+
+use crate::intervals::Interval;
+use crate::testing::lcg;
+
 /// Test Interval Vec.
 macro_rules! tiv {
     () => (
@@ -18,3 +23,19 @@ macro_rules! tiv {
 }
 
 pub(crate) use tiv;
+
+/// Sorted [Interval]s where no two overlap, but may touch.
+pub(crate) fn random_disjoint(seed: &mut u64, len: usize, max_gap: u64) -> Vec<Interval<u32>> {
+    let mut start = lcg(seed) % max_gap;
+    (0..len)
+        .map(|_| {
+            let end = start + 1 + lcg(seed) % 6;
+            let interval = Interval {
+                start: start as u32,
+                end: end as u32,
+            };
+            start = end + lcg(seed) % max_gap;
+            interval
+        })
+        .collect()
+}
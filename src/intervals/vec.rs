@@ -1,9 +1,13 @@
 use alloc::vec::Vec;
-use core::ops::{Index, Sub};
+use core::ops::{Add, Index, Rem, Sub};
 
 use super::{
-    get_gaps_intervals, get_length_of_each_interval, insert_interval_and_merge_pre_sorted,
-    intersection_interval_iters, merge_interval_iters, sort_and_merge_overlapping, Interval,
+    bitset_to_intervals, dilate, drop_shorter_than, erode, find_common_free_slot, find_free_slot,
+    find_free_slot_with_buffer, free_slots, get_gaps_intervals, get_length_of_each_interval,
+    insert_interval_and_merge_pre_sorted, intersection_interval_iters, intervals_to_bitset,
+    intervals_to_mask, k_nearest_intervals, mask_to_intervals, merge_interval_iters, merge_within,
+    nearest_gap, nearest_interval, shift, shift_back, sort_and_merge_overlapping, Interval,
+    SaturatingSub,
 };
 
 /// Simple Vec wrapper that ensures it's always sorted, and contains no overlaps
@@ -83,6 +87,124 @@ where
     pub fn interval_lengths(&self) -> Vec<T> {
         get_length_of_each_interval(&self.v)
     }
+
+    pub fn free_slots(&self, duration: T, window: Interval<T>) -> Self {
+        Self {
+            v: free_slots(&self.v, duration, window),
+        }
+    }
+
+    pub fn drop_shorter_than(&mut self, min_len: T) {
+        drop_shorter_than(&mut self.v, min_len)
+    }
+
+    pub fn shift_back(&mut self, offset: T) {
+        shift_back(&mut self.v, offset)
+    }
+}
+
+impl<T> IntervalVec<T>
+where
+    T: Copy + Ord + Add<Output = T>,
+{
+    pub fn merge_within(&mut self, max_gap: T) {
+        merge_within(&mut self.v, max_gap)
+    }
+
+    pub fn shift(&mut self, offset: T) {
+        shift(&mut self.v, offset)
+    }
+}
+
+impl<T> IntervalVec<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T> + SaturatingSub,
+{
+    pub fn dilate(&mut self, pad_before: T, pad_after: T) {
+        self.v = dilate(&self.v, pad_before, pad_after);
+    }
+
+    pub fn erode(&mut self, shrink_before: T, shrink_after: T) {
+        self.v = erode(&self.v, shrink_before, shrink_after);
+    }
+}
+
+impl<T> IntervalVec<T>
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+{
+    pub fn find_free_slot(
+        &self,
+        duration: T,
+        window: Interval<T>,
+        alignment: Option<T>,
+    ) -> Option<Interval<T>> {
+        find_free_slot(&self.v, duration, window, alignment)
+    }
+
+    pub fn find_free_slot_with_buffer(
+        &self,
+        duration: T,
+        window: Interval<T>,
+        alignment: Option<T>,
+        buffer: T,
+    ) -> Option<Interval<T>> {
+        find_free_slot_with_buffer(&self.v, duration, window, alignment, buffer)
+    }
+
+    /// Find the earliest slot that is free in all calendars.
+    pub fn find_common_free_slot(
+        calendars: &[IntervalVec<T>],
+        duration: T,
+        window: Interval<T>,
+        alignment: Option<T>,
+    ) -> Option<Interval<T>> {
+        find_common_free_slot(calendars, duration, window, alignment)
+    }
+}
+
+impl<T> IntervalVec<T>
+where
+    T: Copy + Ord + Default + Sub<Output = T>,
+{
+    /// Find the [Interval] closest to `t`. See <code>[nearest_interval]</code>.
+    pub fn nearest(&self, t: T) -> Option<(usize, T)> {
+        nearest_interval(&self.v, t)
+    }
+
+    pub fn k_nearest(&self, t: T, k: usize) -> Vec<(usize, T)> {
+        k_nearest_intervals(&self.v, t, k)
+    }
+
+    /// Find the free gap closest to `t`. Gap `i` is the free time before [Interval] `i`, see
+    /// <code>[nearest_gap]</code>.
+    pub fn nearest_gap(&self, t: T) -> (usize, T) {
+        nearest_gap(&self.v, t)
+    }
+}
+
+impl IntervalVec<usize> {
+    /// Get the runs of `true` in a mask.
+    pub fn from_mask(mask: &[bool]) -> Self {
+        Self {
+            v: mask_to_intervals(mask),
+        }
+    }
+
+    pub fn to_mask(&self, len: usize) -> Vec<bool> {
+        intervals_to_mask(&self.v, len)
+    }
+
+    /// Get the runs of set bits in a bitset. See <code>[bitset_to_intervals]</code> for the layout.
+    pub fn from_bitset(words: &[u64]) -> Self {
+        Self {
+            v: bitset_to_intervals(words),
+        }
+    }
+
+    pub fn to_bitset(&self, len: usize) -> Vec<u64> {
+        intervals_to_bitset(&self.v, len)
+    }
 }
 
 impl<T> Index<usize> for IntervalVec<T>
@@ -96,6 +218,15 @@ where
     }
 }
 
+impl<T> AsRef<[Interval<T>]> for IntervalVec<T>
+where
+    T: Copy + Ord,
+{
+    fn as_ref(&self) -> &[Interval<T>] {
+        &self.v
+    }
+}
+
 impl<T> From<Vec<Interval<T>>> for IntervalVec<T>
 where
     T: Copy + Ord,
@@ -111,8 +242,8 @@ impl<T> From<Vec<Vec<Interval<T>>>> for IntervalVec<T>
 where
     T: Copy + Ord,
 {
-    fn from(mut value: Vec<Vec<Interval<T>>>) -> Self {
-        let value = merge_interval_iters(&mut value);
+    fn from(value: Vec<Vec<Interval<T>>>) -> Self {
+        let value = merge_interval_iters(&value);
         Self { v: value }
     }
 }
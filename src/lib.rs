@@ -2,5 +2,8 @@
 extern crate alloc;
 
 pub mod ds;
+pub mod graph;
 pub mod intervals;
 pub mod search;
+#[cfg(test)]
+mod testing;
@@ -1,46 +1,261 @@
 use core::cmp::Ordering;
+use core::ops::Range;
 
+/// Find `target` in a sorted `slice`.
+///
+/// Returns the index of the first occurrence, or `None` if `target` isn't in `slice`.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log n) |
+///
+/// Where n = len(slice)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::binary_search;
+///
+/// assert_eq!(binary_search(&[1, 3, 3, 3, 7], &3), Some(1));
+/// assert_eq!(binary_search(&[1, 3, 3, 3, 7], &4), None);
+/// ```
+///
+/// assumptions:
+/// `slice` is sorted.
 pub fn binary_search<T>(slice: &[T], target: &T) -> Option<usize>
 where
     T: Ord,
 {
-    let mut left = 0_usize;
-    let mut right = slice.len() as isize - 1;
-    while left as isize <= right {
-        let middle = ((right as usize - left) / 2) + left;
-        if &slice[middle] < target {
+    binary_search_result(slice, target).ok()
+}
+
+/// <code>[binary_search]</code> where `f` compares an element to the target.
+///
+/// Returns the index of the first element where `f` gives [Ordering::Equal].
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log n) |
+///
+/// Where n = len(slice)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::binary_search_with;
+///
+/// let slice = [(1, 'a'), (4, 'b'), (4, 'c'), (9, 'd')];
+/// assert_eq!(binary_search_with(&slice, |(key, _)| key.cmp(&4)), Some(1));
+/// ```
+///
+/// assumptions:
+/// `f` gives [Ordering::Less], then [Ordering::Equal], then [Ordering::Greater] along `slice`.
+pub fn binary_search_with<T, F>(slice: &[T], f: F) -> Option<usize>
+where
+    F: Fn(&T) -> Ordering,
+{
+    binary_search_with_result(slice, f).ok()
+}
+
+/// <code>[binary_search]</code> that also says where `target` would go.
+///
+/// Returns `Ok` with the index of the first occurrence of `target`, or `Err` with the index
+/// where it could be inserted to keep `slice` sorted.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log n) |
+///
+/// Where n = len(slice)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::binary_search_result;
+///
+/// let slice = [1, 3, 3, 7];
+/// assert_eq!(binary_search_result(&slice, &3), Ok(1));
+/// assert_eq!(binary_search_result(&slice, &5), Err(3));
+/// assert_eq!(binary_search_result(&slice, &8), Err(4));
+/// ```
+///
+/// assumptions:
+/// `slice` is sorted.
+pub fn binary_search_result<T>(slice: &[T], target: &T) -> Result<usize, usize>
+where
+    T: Ord,
+{
+    binary_search_with_result(slice, |item| item.cmp(target))
+}
+
+/// <code>[binary_search_with]</code> that also says where the target would go.
+///
+/// Returns `Ok` with the index of the first element where `f` gives [Ordering::Equal], or `Err`
+/// with the index of the first element where it gives [Ordering::Greater].
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log n) |
+///
+/// Where n = len(slice)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::binary_search_with_result;
+///
+/// let slice = [(1, 'a'), (4, 'b'), (9, 'c')];
+/// assert_eq!(binary_search_with_result(&slice, |(key, _)| key.cmp(&5)), Err(2));
+/// ```
+///
+/// assumptions:
+/// `f` gives [Ordering::Less], then [Ordering::Equal], then [Ordering::Greater] along `slice`.
+pub fn binary_search_with_result<T, F>(slice: &[T], f: F) -> Result<usize, usize>
+where
+    F: Fn(&T) -> Ordering,
+{
+    let index = partition_point(slice, |item| f(item) == Ordering::Less);
+    match slice.get(index).map(&f) {
+        Some(Ordering::Equal) => Ok(index),
+        _ => Err(index),
+    }
+}
+
+/// Get the index of the first element where `pred` is false.
+///
+/// Returns `slice.len()` if `pred` holds for every element.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log n) |
+///
+/// Where n = len(slice)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::partition_point;
+///
+/// assert_eq!(partition_point(&[2, 4, 6, 7, 9], |x| x % 2 == 0), 3);
+/// assert_eq!(partition_point(&[2, 4], |x| x % 2 == 0), 2);
+/// ```
+///
+/// assumptions:
+/// `pred` holds for a prefix of `slice`, and for nothing after it.
+pub fn partition_point<T, P>(slice: &[T], pred: P) -> usize
+where
+    P: Fn(&T) -> bool,
+{
+    // Invariant: pred holds before left, and doesn't hold from right on.
+    let mut left = 0;
+    let mut right = slice.len();
+    while left < right {
+        let middle = left + (right - left) / 2;
+        if pred(&slice[middle]) {
             left = middle + 1;
-        } else if &slice[middle] > target {
-            right = middle as isize - 1;
         } else {
-            return Some(middle);
+            right = middle;
         }
     }
-    None
+    left
 }
 
-pub fn binary_search_with<T, F>(slice: &[T], f: F) -> Option<usize>
+/// Get the index of the first element that isn't smaller than `target`.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log n) |
+///
+/// Where n = len(slice)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::lower_bound;
+///
+/// assert_eq!(lower_bound(&[1, 3, 3, 7], &3), 1);
+/// assert_eq!(lower_bound(&[1, 3, 3, 7], &4), 3);
+/// ```
+///
+/// assumptions:
+/// `slice` is sorted.
+pub fn lower_bound<T>(slice: &[T], target: &T) -> usize
 where
     T: Ord,
-    F: Fn(&T) -> Ordering,
 {
-    let mut left = 0_isize;
-    let mut right = slice.len() as isize - 1;
-    while left <= right {
-        let middle = (((right - left) / 2) + left) as usize;
-        match f(&slice[middle]) {
-            Ordering::Less => {
-                left = middle as isize + 1;
-            }
-            Ordering::Greater => {
-                right = middle as isize - 1;
-            }
-            Ordering::Equal => {
-                return Some(middle);
-            }
-        };
-    }
-    None
+    partition_point(slice, |item| item < target)
+}
+
+/// Get the index of the first element that is larger than `target`.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log n) |
+///
+/// Where n = len(slice)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::upper_bound;
+///
+/// assert_eq!(upper_bound(&[1, 3, 3, 7], &3), 3);
+/// assert_eq!(upper_bound(&[1, 3, 3, 7], &0), 0);
+/// ```
+///
+/// assumptions:
+/// `slice` is sorted.
+pub fn upper_bound<T>(slice: &[T], target: &T) -> usize
+where
+    T: Ord,
+{
+    partition_point(slice, |item| item <= target)
+}
+
+/// Get the range of indices of the elements equal to `target`.
+///
+/// The range is empty, and starts where `target` would be inserted, if there are none.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log n) |
+///
+/// Where n = len(slice)
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::equal_range;
+///
+/// assert_eq!(equal_range(&[1, 3, 3, 7], &3), 1..3);
+/// assert_eq!(equal_range(&[1, 3, 3, 7], &5), 3..3);
+/// ```
+///
+/// assumptions:
+/// `slice` is sorted.
+pub fn equal_range<T>(slice: &[T], target: &T) -> Range<usize>
+where
+    T: Ord,
+{
+    let start = lower_bound(slice, target);
+    // Everything before start is smaller, so only the rest needs searching.
+    start..start + upper_bound(&slice[start..], target)
 }
 
 #[cfg(test)]
@@ -48,7 +263,9 @@ mod tests {
     #![allow(deprecated)]
 
     use super::*;
+    use crate::testing::lcg;
     use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     fn simple() {
@@ -142,4 +359,40 @@ mod tests {
         let comparison = |(a, _): &(i32, &str)| target.cmp(&a);
         assert_eq!(binary_search_with(slice, comparison), Some(0));
     }
+
+    #[test]
+    fn repeated_elements_with_first_occurrence() {
+        let slice = &[2, 2, 2, 2, 2, 2, 2, 4];
+        assert_eq!(binary_search_with(slice, |a| a.cmp(&2)), Some(0));
+        assert_eq!(binary_search(slice, &2), Some(0));
+    }
+
+    #[test]
+    fn result_insertion_points() {
+        let slice = &[1, 3, 5];
+        assert_eq!(binary_search_result(slice, &0), Err(0));
+        assert_eq!(binary_search_result(slice, &4), Err(2));
+        assert_eq!(binary_search_result(slice, &6), Err(3));
+        assert_eq!(binary_search_result(&[] as &[i32], &6), Err(0));
+    }
+
+    #[test]
+    fn bounds_match_linear_scan() {
+        let mut seed = 67;
+        for _ in 0..200 {
+            let len = lcg(&mut seed) as usize % 20;
+            let mut slice: Vec<u32> = (0..len).map(|_| lcg(&mut seed) as u32 % 8).collect();
+            slice.sort_unstable();
+            for target in 0..9 {
+                let lower = slice.iter().filter(|&&x| x < target).count();
+                let upper = slice.iter().filter(|&&x| x <= target).count();
+                assert_eq!(lower_bound(&slice, &target), lower);
+                assert_eq!(upper_bound(&slice, &target), upper);
+                assert_eq!(equal_range(&slice, &target), lower..upper);
+                assert_eq!(slice.partition_point(|&x| x < target), lower);
+                let expected = if lower < upper { Ok(lower) } else { Err(lower) };
+                assert_eq!(binary_search_result(&slice, &target), expected);
+            }
+        }
+    }
 }
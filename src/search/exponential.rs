@@ -0,0 +1,194 @@
+use core::cmp::Ordering;
+
+use super::partition_point;
+
+/// <code>[partition_point]</code> that searches from the front of `slice`, doubling its step.
+///
+/// Costs O(log k) instead of O(log n), where k is the returned index, so it's faster when the
+/// answer is near the front. Walking a large slice in small steps this way costs
+/// O(m * log(n / m)) for m steps, instead of O(n) one element at a time.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log k) |
+///
+/// Where k = the returned index
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::gallop_partition_point;
+///
+/// let slice: Vec<u32> = (0..1000).collect();
+/// assert_eq!(gallop_partition_point(&slice, |&x| x < 3), 3);
+/// assert_eq!(gallop_partition_point(&slice, |_| true), 1000);
+/// ```
+///
+/// assumptions:
+/// `pred` holds for a prefix of `slice`, and for nothing after it.
+pub fn gallop_partition_point<T, P>(slice: &[T], pred: P) -> usize
+where
+    P: Fn(&T) -> bool,
+{
+    // Invariant: pred holds before lo.
+    let mut lo = 0;
+    let mut hi = 1;
+    while hi <= slice.len() && pred(&slice[hi - 1]) {
+        lo = hi;
+        hi *= 2;
+    }
+    // pred doesn't hold at hi - 1, if it's in bounds.
+    let end = (hi - 1).min(slice.len());
+    lo + partition_point(&slice[lo..end], pred)
+}
+
+/// Get the index of the first element that isn't smaller than `target`, galloping from the
+/// front.
+///
+/// Same result as <code>[lower_bound]</code>, in O(log k) where k is the result.
+///
+/// [lower_bound]: super::lower_bound
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log k) |
+///
+/// Where k = the returned index
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::gallop_left;
+///
+/// assert_eq!(gallop_left(&[1, 3, 3, 7], &3), 1);
+/// assert_eq!(gallop_left(&[1, 3, 3, 7], &8), 4);
+/// ```
+///
+/// assumptions:
+/// `slice` is sorted.
+pub fn gallop_left<T>(slice: &[T], target: &T) -> usize
+where
+    T: Ord,
+{
+    gallop_partition_point(slice, |item| item < target)
+}
+
+/// Get the index of the first element that is larger than `target`, galloping from the front.
+///
+/// Same result as <code>[upper_bound]</code>, in O(log k) where k is the result.
+///
+/// [upper_bound]: super::upper_bound
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log k) |
+///
+/// Where k = the returned index
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::gallop_right;
+///
+/// assert_eq!(gallop_right(&[1, 3, 3, 7], &3), 3);
+/// assert_eq!(gallop_right(&[1, 3, 3, 7], &0), 0);
+/// ```
+///
+/// assumptions:
+/// `slice` is sorted.
+pub fn gallop_right<T>(slice: &[T], target: &T) -> usize
+where
+    T: Ord,
+{
+    gallop_partition_point(slice, |item| item <= target)
+}
+
+/// Find `target` in a sorted `slice`, galloping from the front.
+///
+/// Same result as <code>[binary_search_result]</code>: `Ok` with the index of the first
+/// occurrence, or `Err` with where `target` could be inserted. Costs O(log k) where k is that
+/// index, so it suits unbounded or very large slices where the target is near the front.
+///
+/// [binary_search_result]: super::binary_search_result
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(log k) |
+///
+/// Where k = the returned index
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::exponential_search;
+///
+/// let slice: Vec<u64> = (0..1_000_000).map(|x| x * 2).collect();
+/// assert_eq!(exponential_search(&slice, &10), Ok(5));
+/// assert_eq!(exponential_search(&slice, &11), Err(6));
+/// ```
+///
+/// assumptions:
+/// `slice` is sorted.
+pub fn exponential_search<T>(slice: &[T], target: &T) -> Result<usize, usize>
+where
+    T: Ord,
+{
+    let index = gallop_left(slice, target);
+    match slice.get(index).map(|item| item.cmp(target)) {
+        Some(Ordering::Equal) => Ok(index),
+        _ => Err(index),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::{binary_search_result, lower_bound, upper_bound};
+    use crate::testing::lcg;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn empty() {
+        let slice: &[u32] = &[];
+        assert_eq!(gallop_partition_point(slice, |_| true), 0);
+        assert_eq!(exponential_search(slice, &1), Err(0));
+    }
+
+    #[test]
+    fn matches_binary_search() {
+        let mut seed = 79;
+        for _ in 0..300 {
+            let len = lcg(&mut seed) as usize % 70;
+            let mut slice: Vec<u32> = (0..len).map(|_| lcg(&mut seed) as u32 % 40).collect();
+            slice.sort_unstable();
+            for target in 0..41 {
+                assert_eq!(gallop_left(&slice, &target), lower_bound(&slice, &target));
+                assert_eq!(gallop_right(&slice, &target), upper_bound(&slice, &target));
+                assert_eq!(
+                    exponential_search(&slice, &target),
+                    binary_search_result(&slice, &target)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn near_front_is_cheap() {
+        let slice: Vec<u32> = (0..1_000_000).collect();
+        let calls = core::cell::Cell::new(0);
+        let index = gallop_partition_point(&slice, |&x| {
+            calls.set(calls.get() + 1);
+            x < 5
+        });
+        assert_eq!(index, 5);
+        assert!(calls.get() <= 6);
+    }
+}
@@ -1,3 +1,7 @@
 mod binary_search;
+mod exponential;
+mod predicate;
 
 pub use binary_search::*;
+pub use exponential::*;
+pub use predicate::*;
@@ -0,0 +1,344 @@
+/// Integer types that <code>[first_true]</code> and <code>[last_true]</code> can search.
+///
+/// Midpoints are computed with bit operations, so they never overflow, even between the smallest
+/// and the largest value of the type.
+pub trait Searchable: Copy + Ord {
+    /// Midpoint of `self` and `other`, rounded down.
+    fn midpoint_floor(self, other: Self) -> Self;
+    /// Midpoint of `self` and `other`, rounded up.
+    fn midpoint_ceil(self, other: Self) -> Self;
+    /// The next value. Only called on values below the largest one.
+    fn successor(self) -> Self;
+    /// The previous value. Only called on values above the smallest one.
+    fn predecessor(self) -> Self;
+}
+
+macro_rules! impl_searchable {
+    ($($t:ty),*) => {
+        $(
+            impl Searchable for $t {
+                fn midpoint_floor(self, other: Self) -> Self {
+                    (self & other) + ((self ^ other) >> 1)
+                }
+                fn midpoint_ceil(self, other: Self) -> Self {
+                    (self | other) - ((self ^ other) >> 1)
+                }
+                fn successor(self) -> Self {
+                    self + 1
+                }
+                fn predecessor(self) -> Self {
+                    self - 1
+                }
+            }
+        )*
+    };
+}
+
+impl_searchable!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Get the smallest value in `lo..=hi` where `pred` holds.
+///
+/// This is binary search on the answer: `pred` says whether a candidate answer is good enough,
+/// like whether a capacity fits, or whether a condition holds by a time. Returns `None` if `pred`
+/// doesn't hold anywhere in the range, or if the range is empty.
+///
+/// # Complexity:
+///
+/// | Space | Runtime      |
+/// |-------|--------------|
+/// | O(1)  | O(log n) * p |
+///
+/// Where n = hi - lo, p = runtime of pred
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::intervals::Interval;
+/// use kupsy::search::first_true;
+///
+/// // The earliest time by which 3 intervals have ended.
+/// let intervals = [
+///     Interval {start: 0, end: 40},
+///     Interval {start: 5, end: 12},
+///     Interval {start: 3, end: 30},
+///     Interval {start: 9, end: 17},
+/// ];
+/// let ended_by = |time: u32| intervals.iter().filter(|interval| interval.end <= time).count();
+/// assert_eq!(first_true(0, 100, |time| ended_by(time) >= 3), Some(30));
+/// assert_eq!(first_true(0, 100, |time| ended_by(time) >= 5), None);
+/// ```
+///
+/// assumptions:
+/// `pred` is false, then true, along the range.
+pub fn first_true<T, P>(lo: T, hi: T, mut pred: P) -> Option<T>
+where
+    T: Searchable,
+    P: FnMut(T) -> bool,
+{
+    if lo > hi {
+        return None;
+    }
+    let (mut lo, mut hi) = (lo, hi);
+    while lo < hi {
+        let middle = lo.midpoint_floor(hi);
+        if pred(middle) {
+            hi = middle;
+        } else {
+            // middle < hi, so this can't overflow.
+            lo = middle.successor();
+        }
+    }
+    pred(lo).then_some(lo)
+}
+
+/// Get the largest value in `lo..=hi` where `pred` holds.
+///
+/// Returns `None` if `pred` doesn't hold anywhere in the range, or if the range is empty.
+///
+/// # Complexity:
+///
+/// | Space | Runtime      |
+/// |-------|--------------|
+/// | O(1)  | O(log n) * p |
+///
+/// Where n = hi - lo, p = runtime of pred
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::last_true;
+///
+/// // The largest square root that fits, over the whole type.
+/// assert_eq!(last_true(0, u64::MAX, |x| x.checked_mul(x).is_some_and(|square| square <= 1000)), Some(31));
+/// assert_eq!(last_true(i8::MIN, i8::MAX, |x| x < -100), Some(-101));
+/// ```
+///
+/// assumptions:
+/// `pred` is true, then false, along the range.
+pub fn last_true<T, P>(lo: T, hi: T, mut pred: P) -> Option<T>
+where
+    T: Searchable,
+    P: FnMut(T) -> bool,
+{
+    if lo > hi {
+        return None;
+    }
+    let (mut lo, mut hi) = (lo, hi);
+    while lo < hi {
+        let middle = lo.midpoint_ceil(hi);
+        if pred(middle) {
+            lo = middle;
+        } else {
+            // middle > lo, so this can't overflow.
+            hi = middle.predecessor();
+        }
+    }
+    pred(lo).then_some(lo)
+}
+
+/// When <code>[first_true_float]</code> and <code>[last_true_float]</code> stop.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Precision {
+    /// Halve the range this many times.
+    Iterations(u32),
+    /// Halve the range until it's at most this wide.
+    Epsilon(f64),
+}
+
+/// Approximate the smallest value in `lo..=hi` where `pred` holds.
+///
+/// Returns a value where `pred` holds, within the `precision` of the smallest one. Also stops
+/// when the range can't be halved any further. Returns `None` if `pred` doesn't hold at `hi`.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(k) * p |
+///
+/// Where k = number of iterations, at most log2((hi - lo) / epsilon), p = runtime of pred
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::{Precision, first_true_float};
+///
+/// let root = first_true_float(0.0, 2.0, |x| x * x >= 2.0, Precision::Epsilon(1e-9)).unwrap();
+/// assert!(root * root >= 2.0 && (root - core::f64::consts::SQRT_2) < 1e-9);
+/// ```
+///
+/// assumptions:
+/// `pred` is false, then true, along the range.
+pub fn first_true_float<P>(lo: f64, hi: f64, mut pred: P, precision: Precision) -> Option<f64>
+where
+    P: FnMut(f64) -> bool,
+{
+    if lo > hi || !pred(hi) {
+        return None;
+    }
+    let (mut lo, mut hi) = (lo, hi);
+    let mut iterations = 0;
+    while let Some(middle) = float_midpoint(lo, hi, iterations, precision) {
+        if pred(middle) {
+            hi = middle;
+        } else {
+            lo = middle;
+        }
+        iterations += 1;
+    }
+    Some(hi)
+}
+
+/// Approximate the largest value in `lo..=hi` where `pred` holds.
+///
+/// Returns a value where `pred` holds, within the `precision` of the largest one. Also stops when
+/// the range can't be halved any further. Returns `None` if `pred` doesn't hold at `lo`.
+///
+/// # Complexity:
+///
+/// | Space | Runtime  |
+/// |-------|----------|
+/// | O(1)  | O(k) * p |
+///
+/// Where k = number of iterations, at most log2((hi - lo) / epsilon), p = runtime of pred
+///
+/// # Examples
+///
+/// ```
+/// use kupsy::search::{Precision, last_true_float};
+///
+/// // The longest interval that still fits 3 times in 10.
+/// let length = last_true_float(0.0, 10.0, |length| 3.0 * length <= 10.0, Precision::Iterations(60)).unwrap();
+/// assert!(3.0 * length <= 10.0 && 10.0 / 3.0 - length < 1e-12);
+/// ```
+///
+/// assumptions:
+/// `pred` is true, then false, along the range.
+pub fn last_true_float<P>(lo: f64, hi: f64, mut pred: P, precision: Precision) -> Option<f64>
+where
+    P: FnMut(f64) -> bool,
+{
+    if lo > hi || !pred(lo) {
+        return None;
+    }
+    let (mut lo, mut hi) = (lo, hi);
+    let mut iterations = 0;
+    while let Some(middle) = float_midpoint(lo, hi, iterations, precision) {
+        if pred(middle) {
+            lo = middle;
+        } else {
+            hi = middle;
+        }
+        iterations += 1;
+    }
+    Some(lo)
+}
+
+/// Midpoint of `lo` and `hi`, or `None` once `precision` is reached or there's no value between
+/// them.
+fn float_midpoint(lo: f64, hi: f64, iterations: u32, precision: Precision) -> Option<f64> {
+    let done = match precision {
+        Precision::Iterations(limit) => iterations >= limit,
+        Precision::Epsilon(epsilon) => hi - lo <= epsilon,
+    };
+    // Halving both first can't overflow, unlike lo + hi.
+    let middle = lo / 2.0 + hi / 2.0;
+    (!done && lo < middle && middle < hi).then_some(middle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::lcg;
+
+    #[test]
+    fn empty_and_never_true() {
+        assert_eq!(first_true(5, 4, |_| true), None);
+        assert_eq!(last_true(5, 4, |_| true), None);
+        assert_eq!(first_true(0, 10, |_| false), None);
+        assert_eq!(last_true(0, 10, |_| false), None);
+        assert_eq!(first_true(3_u8, 3, |_| true), Some(3));
+    }
+
+    #[test]
+    fn extremes_do_not_overflow() {
+        assert_eq!(first_true(i64::MIN, i64::MAX, |x| x >= 5), Some(5));
+        assert_eq!(first_true(i64::MIN, i64::MAX, |_| true), Some(i64::MIN));
+        assert_eq!(
+            first_true(i64::MIN, i64::MAX, |x| x == i64::MAX),
+            Some(i64::MAX)
+        );
+        assert_eq!(last_true(i64::MIN, i64::MAX, |_| true), Some(i64::MAX));
+        assert_eq!(
+            last_true(i64::MIN, i64::MAX, |x| x == i64::MIN),
+            Some(i64::MIN)
+        );
+        assert_eq!(
+            last_true(0, u128::MAX, |x| x < u128::MAX),
+            Some(u128::MAX - 1)
+        );
+        assert_eq!(first_true(u8::MIN, u8::MAX, |x| x > 200), Some(201));
+    }
+
+    #[test]
+    fn midpoints() {
+        assert_eq!((-3_i32).midpoint_floor(0), -2);
+        assert_eq!((-3_i32).midpoint_ceil(0), -1);
+        assert_eq!(i8::MIN.midpoint_floor(i8::MAX), -1);
+        assert_eq!(i8::MIN.midpoint_ceil(i8::MAX), 0);
+        assert_eq!(u8::MAX.midpoint_floor(u8::MAX - 1), u8::MAX - 1);
+        assert_eq!(u8::MAX.midpoint_ceil(u8::MAX - 1), u8::MAX);
+    }
+
+    #[test]
+    fn matches_linear_scan() {
+        let mut seed = 73;
+        for _ in 0..500 {
+            let lo = lcg(&mut seed) as i32 % 50 - 25;
+            let hi = lo + lcg(&mut seed) as i32 % 40 - 5;
+            let threshold = lcg(&mut seed) as i32 % 80 - 40;
+            let mut calls = 0;
+            assert_eq!(
+                first_true(lo, hi, |x| {
+                    calls += 1;
+                    x >= threshold
+                }),
+                (lo..=hi).find(|&x| x >= threshold)
+            );
+            assert!(calls <= 7);
+            assert_eq!(
+                last_true(lo, hi, |x| x <= threshold),
+                (lo..=hi).rev().find(|&x| x <= threshold)
+            );
+        }
+    }
+
+    #[test]
+    fn float_limits() {
+        let pred = |x: f64| x >= 0.3;
+        assert_eq!(
+            first_true_float(0.0, 1.0, pred, Precision::Iterations(0)),
+            Some(1.0)
+        );
+        assert_eq!(
+            first_true_float(0.0, 1.0, pred, Precision::Iterations(2)),
+            Some(0.5)
+        );
+        let x = first_true_float(0.0, 1.0, pred, Precision::Epsilon(1e-6)).unwrap();
+        assert!((0.3..0.3 + 1e-6).contains(&x));
+        // An epsilon of 0 stops once the range can't be halved.
+        let x = first_true_float(0.0, 1.0, pred, Precision::Epsilon(0.0)).unwrap();
+        assert_eq!(x, 0.3);
+        assert_eq!(
+            first_true_float(0.0, 1.0, |x| x > 2.0, Precision::Epsilon(0.0)),
+            None
+        );
+        assert_eq!(
+            last_true_float(0.0, 1.0, |x| x < 0.0, Precision::Epsilon(0.0)),
+            None
+        );
+
+        let x = last_true_float(f64::MIN, f64::MAX, |x| x <= 1e300, Precision::Epsilon(0.0));
+        assert_eq!(x, Some(1e300));
+    }
+}
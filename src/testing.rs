@@ -0,0 +1,7 @@
+/// Small deterministic pseudo random numbers, so tests don't need a dependency.
+pub(crate) fn lcg(seed: &mut u64) -> u64 {
+    *seed = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    *seed >> 33
+}